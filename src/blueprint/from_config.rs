@@ -1,6 +1,8 @@
 #![allow(clippy::too_many_arguments)]
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::net::IpAddr;
 
 use async_graphql::parser::types::ConstDirective;
 #[allow(unused_imports)]
@@ -13,37 +15,220 @@ use regex::Regex;
 use super::UnionTypeDefinition;
 use crate::blueprint::Type::ListType;
 use crate::blueprint::*;
-use crate::config::{Arg, Batch, Config, Field, InlineType};
+use crate::config::{Arg, Batch, Config, Dns, Field, InlineType};
 use crate::directive::DirectiveCodec;
 use crate::endpoint::Endpoint;
 use crate::http::Method;
+use crate::jq::JqFilter;
 use crate::json::JsonSchema;
 use crate::lambda::Expression::Literal;
 use crate::lambda::{Expression, Lambda, Operation};
+use crate::mustache::{Mustache, Segment};
 use crate::request_template::RequestTemplate;
 use crate::valid::{OptionExtension, Valid as ValidDefault, ValidExtensions, ValidationError, VectorExtension};
 use crate::{blueprint, config};
 
 type Valid<A> = ValidDefault<A, String>;
 
+const MAX_RETRIES: u32 = 10;
+const STREAMING_DIRECTIVES: &[&str] = &["@sse"];
+
 pub fn config_blueprint(config: &Config) -> Valid<Blueprint> {
+  let resolved = if config.graphql.types.values().any(|type_| type_.spread.is_some()) {
+    let mut resolved = config.clone();
+    resolved.resolve_fragments()?;
+    Cow::Owned(resolved)
+  } else {
+    Cow::Borrowed(config)
+  };
+  validate_type_renames(&resolved)?;
+  let renamed = rename_types(&resolved);
+  let config = renamed.as_ref();
   let output_types = config.output_types();
   let input_types = config.input_types();
   let schema = to_schema(config)?;
+  validate_interface_conformance(config)?;
+  validate_data_loaders(config)?;
   let definitions = to_definitions(config, output_types, input_types)?;
   let server: Server = Server::try_from(config.server.clone())?;
   let upstream = config.upstream.clone();
   valid_base_url(upstream.base_url.as_ref())?;
+  validate_batch(upstream.batch.as_ref())?;
+  validate_dns(upstream.dns.as_ref())?;
   let blueprint = Blueprint { schema, definitions, server, upstream };
   let blueprint = apply_batching(blueprint);
   Ok(super::compress::compress(blueprint))
 }
+fn validate_type_renames(config: &Config) -> Valid<()> {
+  let types = &config.graphql.types;
+  types
+    .iter()
+    .filter_map(|(name, type_)| type_.rename.as_ref().map(|to| (name, to)))
+    .validate_all(|(name, to)| {
+      let collision = types
+        .iter()
+        .find(|(other, type_)| *other != name && type_.rename.as_ref().unwrap_or(other) == to);
+      match collision {
+        Some((other, _)) => Valid::fail(format!(
+          "type '{}' can't be renamed to '{}' because that name is already used by type '{}'",
+          name, to, other
+        ))
+        .trace("@rename")
+        .trace(name),
+        None => Valid::Ok(()),
+      }
+    })?;
+  Ok(())
+}
+fn rename_types(config: &Config) -> Cow<'_, Config> {
+  let renames: HashMap<String, String> = config
+    .graphql
+    .types
+    .iter()
+    .filter_map(|(name, type_)| type_.rename.clone().map(|to| (name.clone(), to)))
+    .collect();
+  if renames.is_empty() {
+    return Cow::Borrowed(config);
+  }
+  let rename = |name: &mut String| {
+    if let Some(to) = renames.get(name) {
+      *name = to.clone();
+    }
+  };
+  let mut config = config.clone();
+  let schema = &mut config.graphql.schema;
+  [&mut schema.query, &mut schema.mutation, &mut schema.subscription]
+    .into_iter()
+    .flatten()
+    .for_each(rename);
+  let types = std::mem::take(&mut config.graphql.types);
+  for (mut name, mut type_) in types {
+    rename(&mut name);
+    for field in type_.fields.values_mut() {
+      rename(&mut field.type_of);
+      field.args.values_mut().for_each(|arg| rename(&mut arg.type_of));
+    }
+    type_.implements = type_
+      .implements
+      .into_iter()
+      .map(|mut name| {
+        rename(&mut name);
+        name
+      })
+      .collect();
+    config.graphql.types.insert(name, type_);
+  }
+  for union_ in config.graphql.unions.values_mut() {
+    union_.types = std::mem::take(&mut union_.types)
+      .into_iter()
+      .map(|mut name| {
+        rename(&mut name);
+        name
+      })
+      .collect();
+  }
+  Cow::Owned(config)
+}
+fn validate_interface_conformance(config: &Config) -> Valid<()> {
+  config.graphql.types.iter().validate_all(|(type_name, type_)| {
+    type_
+      .implements
+      .iter()
+      .filter_map(|name| config.find_type(name).map(|interface| (name, interface)))
+      .validate_all(|(interface_name, interface)| {
+        interface
+          .fields
+          .iter()
+          .filter(|(name, _)| {
+            !type_.fields.iter().any(|(field_name, field)| {
+              let public_name = field.modify.as_ref().and_then(|modify| modify.name.as_ref());
+              public_name.unwrap_or(field_name) == *name
+            })
+          })
+          .validate_all(|(name, field)| {
+            Valid::<()>::fail(format!(
+              "missing field '{}' from interface '{}', add `{}: {}` to the type",
+              name,
+              interface_name,
+              name,
+              field.type_signature()
+            ))
+          })
+      })
+      .trace(type_name)
+  })?;
+  Ok(())
+}
+fn validate_data_loaders(config: &Config) -> Valid<()> {
+  let mut loaders: BTreeMap<&String, (String, Option<Vec<String>>)> = BTreeMap::new();
+  let mut shared = Vec::new();
+  for (type_name, type_) in config.graphql.types.iter() {
+    for (field_name, field) in type_.fields.iter() {
+      if let Some(name) = field.http.as_ref().and_then(|http| http.data_loader.as_ref()) {
+        let group_by = field.group_by.as_ref().map(|group_by| group_by.path());
+        match loaders.get(name) {
+          Some(first) => shared.push((type_name, field_name, name, group_by, first.clone())),
+          None => {
+            loaders.insert(name, (format!("{}.{}", type_name, field_name), group_by));
+          }
+        }
+      }
+    }
+  }
+  shared
+    .into_iter()
+    .validate_all(|(type_name, field_name, name, group_by, (first, first_group_by))| {
+      if group_by == first_group_by {
+        Valid::Ok(())
+      } else {
+        Valid::fail(format!(
+          "data loader '{}' is shared with '{}', which groups by a different key",
+          name, first
+        ))
+        .trace("dataLoader")
+        .trace("@http")
+        .trace(field_name)
+        .trace(type_name)
+      }
+    })?;
+  Ok(())
+}
 fn valid_base_url(base_url: Option<&String>) -> Valid<()> {
-  if let Some(base_url) = base_url {
+  if let Some(base_url) = base_url.filter(|base_url| !Mustache::is_templated(base_url)) {
     reqwest::Url::parse(base_url).map_err(|e| ValidationError::new(e.to_string()))?;
   }
   Ok(())
 }
+fn validate_batch(batch: Option<&Batch>) -> Valid<()> {
+  if let Some(batch) = batch {
+    if batch.max_size == 0 {
+      return Valid::fail("maxSize must be greater than 0".to_string())
+        .trace("maxSize")
+        .trace("batch")
+        .trace("@upstream")
+        .trace("schema");
+    }
+  }
+  Ok(())
+}
+fn validate_dns(dns: Option<&Dns>) -> Valid<()> {
+  if let Some(dns) = dns {
+    dns
+      .servers
+      .iter()
+      .validate_all(|server| {
+        server
+          .parse::<IpAddr>()
+          .map_err(|e| ValidationError::new(format!("Parsing failed because of {}", e)))
+          .trace(server)
+      })
+      .trace("servers")
+      .trace("dns")
+      .trace("@upstream")
+      .trace("schema")?;
+  }
+  Ok(())
+}
 
 pub fn apply_batching(mut blueprint: Blueprint) -> Blueprint {
   for def in blueprint.definitions.iter() {
@@ -83,7 +268,9 @@ fn to_schema(config: &Config) -> Valid<SchemaDefinition> {
     .as_ref()
     .validate_some("Query root is missing".to_owned())?;
 
-  validate_query(config).validate_or(validate_mutation(config))?;
+  validate_query(config)
+    .validate_or(validate_mutation(config))
+    .validate_or(validate_subscription(config))?;
 
   Ok(SchemaDefinition {
     query: query_type_name.clone(),
@@ -112,7 +299,7 @@ fn to_definitions<'a>(
       let definition = to_object_type_definition(name, type_, config).trace(name)?;
       match definition.clone() {
         Definition::ObjectTypeDefinition(object_type_definition) => {
-          if config.input_types().contains(name) {
+          if input_types.contains(name) {
             to_input_object_type_definition(object_type_definition).trace(name)
           } else if type_.interface {
             to_interface_type_definition(object_type_definition).trace(name)
@@ -222,7 +409,7 @@ fn to_field(
   }
 
   let field_type = &field.type_of;
-  let args = to_args(field)?;
+  let args = to_args(field, config)?;
 
   let field_definition = FieldDefinition {
     name: name.to_owned(),
@@ -233,15 +420,29 @@ fn to_field(
     resolver: None,
   };
 
-  let field_definition = update_http(field, field_definition, config).trace("@http")?;
+  let field_definition = update_http(type_of, field, field_definition, config).trace("@http")?;
   let field_definition = update_group_by(field, field_definition).trace("@groupBy")?;
   let field_definition = update_unsafe(field.clone(), field_definition);
   let field_definition = update_const_field(field, field_definition, config).trace("@const")?;
+  let field_definition = update_expr(field, field_definition);
   let field_definition = update_inline_field(type_of, field, field_definition, config).trace("@inline")?;
   let maybe_field_definition = update_modify(field, field_definition, type_of, config).trace("@modify")?;
+  if let Some(field_definition) = maybe_field_definition.as_ref() {
+    validate_field_nullability(field_definition)?;
+  }
   Ok(maybe_field_definition)
 }
 
+// Runs after all the overrides are applied, so that the final type of the field is checked
+fn validate_field_nullability(b_field: &FieldDefinition) -> Valid<()> {
+  match &b_field.resolver {
+    Some(Literal(serde_json::Value::Null)) if !b_field.of_type.is_nullable() => {
+      Valid::fail("required field cannot resolve to null".to_string())
+    }
+    _ => Valid::Ok(()),
+  }
+}
+
 fn to_type(name: &str, list: bool, non_null: bool, list_type_required: bool) -> Type {
   if list {
     Type::ListType {
@@ -292,6 +493,40 @@ fn validate_mutation(config: &Config) -> Valid<()> {
   Ok(())
 }
 
+fn validate_subscription(config: &Config) -> Valid<()> {
+  let subscription_type_name = config.graphql.schema.subscription.as_ref();
+
+  if let Some(subscription_type_name) = subscription_type_name {
+    let Some(subscription) = config.find_type(subscription_type_name) else {
+      return Valid::fail("Subscription type is not defined".to_owned()).trace(subscription_type_name);
+    };
+
+    subscription
+      .fields
+      .iter()
+      .validate_all(validate_field_has_streaming_resolver)
+      .trace(subscription_type_name)?;
+  }
+
+  Ok(())
+}
+
+fn validate_field_has_streaming_resolver((name, field): (&String, &Field)) -> Valid<()> {
+  let directives = field.resolvable_directives();
+  if directives
+    .iter()
+    .any(|directive| STREAMING_DIRECTIVES.contains(directive))
+  {
+    Ok(())
+  } else {
+    Valid::fail(format!(
+      "No streaming resolver has been found for the subscription field, expected one of {}",
+      STREAMING_DIRECTIVES.join(", ")
+    ))
+    .trace(name)
+  }
+}
+
 fn validate_field_has_resolver((name, field): (&String, &Field)) -> Valid<()> {
   if field.has_resolver() {
     Ok(())
@@ -342,7 +577,90 @@ fn update_group_by(field: &config::Field, mut b_field: FieldDefinition) -> Valid
   }
 }
 
-fn update_http(field: &config::Field, mut b_field: FieldDefinition, config: &Config) -> Valid<FieldDefinition> {
+fn validate_retries(http: &config::Http) -> Valid<()> {
+  match http.retries {
+    Some(retries) if retries > MAX_RETRIES => {
+      Valid::fail(format!("retries must be less than or equal to {}", MAX_RETRIES))
+    }
+    _ => Valid::Ok(()),
+  }
+}
+
+fn validate_retry_on(http: &config::Http) -> Valid<()> {
+  http.retry_on.iter().flatten().validate_all(|code| {
+    if (100..=599).contains(code) {
+      Valid::Ok(())
+    } else {
+      Valid::fail(format!("'{}' is not a valid HTTP status code", code))
+    }
+  })?;
+  Valid::Ok(())
+}
+
+fn validate_on_error(field: &config::Field, http: &config::Http, config: &Config) -> Valid<()> {
+  match http.on_error.as_ref() {
+    Some(value) if value.is_null() && field.required => {
+      Valid::fail("required field cannot fall back to null".to_string())
+    }
+    Some(value) if !value.is_null() => match ConstValue::from_json(value.to_owned()) {
+      Ok(gql_value) => match to_json_schema_for_field(field, config).validate(&gql_value) {
+        Ok(_) => Valid::Ok(()),
+        Err(err) => err.into(),
+      },
+      Err(e) => Valid::fail(format!("invalid JSON: {}", e)),
+    },
+    _ => Valid::Ok(()),
+  }
+}
+
+fn validate_mock(field: &config::Field, http: &config::Http, config: &Config) -> Valid<()> {
+  match http.mock.as_ref() {
+    Some(mock) => match mock.get("data") {
+      Some(data) if data.is_null() && field.required => {
+        Valid::fail("required field cannot be mocked with null".to_string())
+      }
+      Some(data) if !data.is_null() => match ConstValue::from_json(data.to_owned()) {
+        Ok(gql_value) => match to_json_schema_for_field(field, config).validate(&gql_value) {
+          Ok(_) => Valid::Ok(()),
+          Err(err) => err.into(),
+        },
+        Err(e) => Valid::fail(format!("invalid JSON: {}", e)),
+      },
+      Some(_) => Valid::Ok(()),
+      None => Valid::fail("mock must be an object with a 'data' key".to_string()),
+    },
+    None => Valid::Ok(()),
+  }
+}
+
+fn validate_accept(http: &config::Http) -> Valid<()> {
+  let is_token = |token: &str| {
+    !token.is_empty()
+      && token
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+*".contains(c))
+  };
+  match http.accept.as_ref() {
+    Some(accept) => {
+      let essence = accept.split(';').next().unwrap_or_default().trim();
+      match essence.split_once('/') {
+        Some((type_, subtype)) if is_token(type_) && is_token(subtype) => Valid::Ok(()),
+        _ => Valid::fail(format!(
+          "'{}' is not a valid MIME type, expected a value like 'application/json'",
+          accept
+        )),
+      }
+    }
+    None => Valid::Ok(()),
+  }
+}
+
+fn update_http(
+  type_of: &config::Type,
+  field: &config::Field,
+  mut b_field: FieldDefinition,
+  config: &Config,
+) -> Valid<FieldDefinition> {
   match field.http.as_ref() {
     Some(http) => match http
       .base_url
@@ -354,8 +672,36 @@ fn update_http(field: &config::Field, mut b_field: FieldDefinition, config: &Con
         if base_url.ends_with('/') {
           base_url.pop();
         }
+        validate_path(field, http.path.as_str(), config).trace("path")?;
         base_url.push_str(http.path.clone().as_str());
-        let query = http.query.clone().iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        match http.trailing_slash {
+          Some(true) if !base_url.ends_with('/') => base_url.push('/'),
+          Some(false) => base_url.truncate(base_url.trim_end_matches('/').len()),
+          _ => {}
+        }
+        validate_query_params(type_of, field, config).trace("query")?;
+        validate_headers(type_of, field, config).trace("headers")?;
+        validate_retries(http).trace("retries")?;
+        validate_retry_on(http).trace("retryOn")?;
+        validate_on_error(field, http, config).trace("onError")?;
+        validate_accept(http).trace("accept")?;
+        validate_mock(field, http, config).trace("mock")?;
+        if let Some(data) = http.mock.as_ref().and_then(|mock| mock.get("data")) {
+          // mocked fields never reach the upstream, the canned data is served like `@const`
+          b_field.resolver = Some(Literal(data.clone()));
+          return Valid::Ok(b_field);
+        }
+        let query = http
+          .query
+          .iter()
+          .map(|param| (param.key.clone(), param.value.clone()))
+          .collect();
+        let expand = http
+          .query
+          .iter()
+          .filter(|param| param.expand)
+          .map(|param| param.key.clone())
+          .collect();
         let output_schema = to_json_schema_for_field(field, config);
         let input_schema = to_json_schema_for_args(&field.args, config);
         let mut header_map = HeaderMap::new();
@@ -365,14 +711,33 @@ fn update_http(field: &config::Field, mut b_field: FieldDefinition, config: &Con
             HeaderValue::from_str(v.as_str()).map_err(|e| ValidationError::new(e.to_string()))?,
           );
         }
+        if let Some(accept) = http.accept.as_ref() {
+          if !header_map.contains_key(hyper::header::ACCEPT) {
+            header_map.insert(
+              hyper::header::ACCEPT,
+              HeaderValue::from_str(accept).map_err(|e| ValidationError::new(e.to_string()))?,
+            );
+          }
+        }
+        let jq = http
+          .jq
+          .as_deref()
+          .map(JqFilter::parse)
+          .transpose()
+          .map_err(ValidationError::new)?;
         let req_template = RequestTemplate::try_from(
           Endpoint::new(base_url.to_string())
             .method(http.method.clone())
             .query(query)
+            .expand(expand)
+            .query_encoding(http.get_query_encoding())
             .output(output_schema)
             .input(input_schema)
             .body(http.body.clone())
-            .headers(header_map),
+            .headers(header_map)
+            .decode(http.decode.clone())
+            .allowed_headers(Some(config.allowed_headers(http)))
+            .jq(jq),
         )
         .map_err(|e| ValidationError::new(e.to_string()))?;
 
@@ -385,6 +750,155 @@ fn update_http(field: &config::Field, mut b_field: FieldDefinition, config: &Con
     None => Valid::Ok(b_field),
   }
 }
+fn validate_path(field: &config::Field, path: &str, config: &Config) -> Valid<()> {
+  let mustache = Mustache::parse(path).map_err(|e| ValidationError::new(e.to_string()))?;
+  mustache
+    .section_segments()
+    .into_iter()
+    .validate_all(|parts| match parts.as_slice() {
+      [head, name, ..] if head == "args" && !field.args.contains_key(name) => Valid::fail(format!(
+        "argument '{}' is not defined, it can't be used in a path section",
+        name
+      )),
+      _ => Valid::Ok(()),
+    })?;
+  let guarded = mustache.guarded_segments();
+  let mut expressions = mustache.expression_segments();
+  expressions.dedup();
+  expressions
+    .into_iter()
+    .validate_all(|parts| match parts.split_first() {
+      Some((head, tail)) if head == "args" => match tail.split_first() {
+        Some((name, rest)) => match field.args.get(name) {
+          Some(arg) if !arg.required && arg.default_value.is_none() && !guarded.contains(&parts) => {
+            Valid::fail(format!(
+              "argument '{}' is nullable and has no default value, it can't be used in a path segment",
+              name
+            ))
+          }
+          Some(arg) => validate_path_segment(&tail.join("."), &arg.type_of, arg.list, rest, config),
+          None => Valid::Ok(()),
+        },
+        None => Valid::Ok(()),
+      },
+      _ => Valid::Ok(()),
+    })?;
+  Ok(())
+}
+fn validate_path_segment(name: &str, type_of: &str, list: bool, rest: &[String], config: &Config) -> Valid<()> {
+  if list {
+    return Valid::fail(format!(
+      "argument '{}' is a list and can't be used in a path segment",
+      name
+    ));
+  }
+  match rest.split_first() {
+    Some((key, rest)) => match config.find_type(type_of).and_then(|type_| type_.fields.get(key)) {
+      Some(field) => validate_path_segment(name, &field.type_of, field.list, rest, config),
+      None => Valid::Ok(()),
+    },
+    None if !is_scalar_like(type_of, config) => Valid::fail(format!(
+      "argument '{}' of type '{}' can't be used in a path segment",
+      name, type_of
+    )),
+    None => Valid::Ok(()),
+  }
+}
+fn validate_query_params(parent: &config::Type, field: &config::Field, config: &Config) -> Valid<()> {
+  let http = field.http.as_ref();
+  http
+    .map(|http| &http.query)
+    .into_iter()
+    .flat_map(|query| query.iter())
+    .validate_all(|param| {
+      validate_template(parent, field, &param.value, config)
+        .validate_or(validate_expand(field, param))
+        .trace(&param.key)
+    })?;
+  Ok(())
+}
+// Without `expand` a list argument is rendered as one JSON value, so only `expand: true` needs checking.
+fn validate_expand(field: &config::Field, param: &config::QueryParam) -> Valid<()> {
+  if !param.expand {
+    return Valid::Ok(());
+  }
+  let mustache = Mustache::parse(&param.value).map_err(|e| ValidationError::new(e.to_string()))?;
+  let is_list_reference = match mustache.expression_segments().as_slice() {
+    [parts] => match parts.as_slice() {
+      [head, name] => {
+        head == "args"
+          && field.args.get(name).is_some_and(|arg| arg.list)
+          && mustache == Mustache::from(vec![Segment::Expression((*parts).clone())])
+      }
+      _ => false,
+    },
+    _ => false,
+  };
+  if is_list_reference {
+    Valid::Ok(())
+  } else {
+    Valid::fail("expand requires the value to be a single list argument like '{{args.ids}}'".to_string())
+  }
+}
+
+fn validate_headers(parent: &config::Type, field: &config::Field, config: &Config) -> Valid<()> {
+  let http = field.http.as_ref();
+  http
+    .map(|http| &http.headers)
+    .into_iter()
+    .flat_map(|headers| headers.iter())
+    .validate_all(|(key, value)| {
+      if key.trim().is_empty() {
+        Valid::fail("header name cannot be empty".to_string())
+      } else {
+        validate_template(parent, field, value, config).trace(key)
+      }
+    })?;
+  Ok(())
+}
+fn validate_template(parent: &config::Type, field: &config::Field, template: &str, config: &Config) -> Valid<()> {
+  let mustache = Mustache::parse(template).map_err(|e| ValidationError::new(e.to_string()))?;
+  mustache
+    .expression_segments()
+    .into_iter()
+    .validate_all(|parts| match parts.split_first() {
+      Some((head, path)) if head == "value" => validate_parent_path(parent, path, config),
+      Some((head, path)) if head == "args" => validate_args_path(field, path, config),
+      _ => Valid::Ok(()),
+    })?;
+  Ok(())
+}
+fn validate_args_path(field: &config::Field, path: &[String], config: &Config) -> Valid<()> {
+  match path.split_first() {
+    Some((name, rest)) => match field.args.get(name) {
+      Some(arg) if !type_path_exists(&arg.type_of, rest, config) => {
+        Valid::fail(format!("'args.{}' is not a field of '{}'", path.join("."), arg.type_of))
+      }
+      Some(_) => Valid::Ok(()),
+      None => Valid::fail(format!("'args.{}' is not an argument of the field", name)),
+    },
+    None => Valid::Ok(()),
+  }
+}
+fn validate_parent_path(parent: &config::Type, path: &[String], config: &Config) -> Valid<()> {
+  let exists = match path.split_first() {
+    Some((name, rest)) => parent
+      .fields
+      .get(name)
+      .is_some_and(|field| type_path_exists(&field.type_of, rest, config)),
+    None => true,
+  };
+  if exists {
+    Valid::Ok(())
+  } else {
+    Valid::fail(format!("'value.{}' is not a field of the parent type", path.join(".")))
+  }
+}
+// Types without a definition (eg: JSON scalars) have no fields to check, so any path past them is accepted
+fn type_path_exists(type_name: &str, path: &[String], config: &Config) -> bool {
+  let path: Vec<&str> = path.iter().map(String::as_str).collect();
+  config.find_type(type_name).is_none() || config.field_path_exists(type_name, &path)
+}
 fn update_modify(
   field: &config::Field,
   mut b_field: FieldDefinition,
@@ -393,7 +907,8 @@ fn update_modify(
 ) -> Valid<Option<FieldDefinition>> {
   match field.modify.as_ref() {
     Some(modify) => {
-      if modify.omit {
+      // Conditional omits depend on the environment, so the field is kept and the condition is checked at runtime.
+      if modify.omit && modify.when.is_none() {
         Ok(None)
       } else if let Some(new_name) = &modify.name {
         for name in type_.implements.iter() {
@@ -416,10 +931,21 @@ fn update_modify(
     None => Valid::Ok(Some(b_field)),
   }
 }
+fn update_expr(field: &config::Field, mut b_field: FieldDefinition) -> FieldDefinition {
+  if let Some(expr) = field.expr.as_ref() {
+    b_field.resolver = Some(Expression::Template(expr.body.clone()));
+  }
+  b_field
+}
 fn update_const_field(field: &config::Field, mut b_field: FieldDefinition, config: &Config) -> Valid<FieldDefinition> {
   match field.const_field.as_ref() {
     Some(const_field) => {
-      let data = const_field.data.to_owned();
+      let data = const_field.data().to_owned();
+      if data.is_null() {
+        // nullability is checked against the final type in `validate_field_nullability`
+        b_field.resolver = Some(Literal(data));
+        return Valid::Ok(b_field);
+      }
       match ConstValue::from_json(data.to_owned()) {
         Ok(gql_value) => match to_json_schema_for_field(field, config).validate(&gql_value) {
           Ok(_) => {
@@ -437,6 +963,12 @@ fn update_const_field(field: &config::Field, mut b_field: FieldDefinition, confi
 fn is_scalar(type_name: &str) -> bool {
   ["String", "Int", "Float", "Boolean", "ID", "JSON"].contains(&type_name)
 }
+fn is_scalar_like(type_name: &str, config: &Config) -> bool {
+  is_scalar(type_name)
+    || config
+      .find_type(type_name)
+      .is_some_and(|type_| type_.scalar || type_.variants.is_some())
+}
 // Helper function to recursively process the path and return the corresponding type
 fn process_path(
   path: &[String],
@@ -557,13 +1089,8 @@ fn process_field_within_type(
   invalid_path_handler(field_name, remaining_path)
 }
 
-// Main function to update an inline field
-fn update_inline_field(
-  type_info: &config::Type,
-  field: &config::Field,
-  base_field: FieldDefinition,
-  config: &Config,
-) -> Valid<FieldDefinition> {
+// Resolves the type of an inline field by following its path, `Config::flatten_inline_types` shares it
+pub(crate) fn inline_type(type_info: &config::Type, field: &config::Field, config: &Config) -> Valid<Type> {
   let inlined_path = field.inline.as_ref().map(|x| x.path.clone()).unwrap_or_default();
   let handle_invalid_path = |_field_name: &str, _inlined_path: &[String]| -> Valid<Type> {
     Valid::fail("Inline can't be done because provided path doesn't exist".to_string())
@@ -572,28 +1099,34 @@ fn update_inline_field(
     let re = Regex::new(r"^\d+$").unwrap();
     re.is_match(s)
   });
-  if let Some(InlineType { path }) = field.clone().inline {
-    return match process_path(&inlined_path, field, type_info, false, config, &handle_invalid_path) {
-      Valid::Ok(of_type) => {
-        let mut updated_base_field = base_field;
-        let resolver = Lambda::context_path(path.clone());
-        if has_index {
-          updated_base_field.of_type = Type::NamedType { name: of_type.name().to_string(), non_null: false }
-        } else {
-          updated_base_field.of_type = of_type;
-        }
+  let of_type = process_path(&inlined_path, field, type_info, false, config, &handle_invalid_path)?;
+  if has_index {
+    Valid::Ok(Type::NamedType { name: of_type.name().to_string(), non_null: false })
+  } else {
+    Valid::Ok(of_type)
+  }
+}
 
-        updated_base_field = updated_base_field.resolver_or_default(resolver, |r| r.to_input_path(path.clone()));
-        Valid::Ok(updated_base_field)
-      }
-      Valid::Err(err) => Valid::Err(err),
-    };
+// Main function to update an inline field
+fn update_inline_field(
+  type_info: &config::Type,
+  field: &config::Field,
+  base_field: FieldDefinition,
+  config: &Config,
+) -> Valid<FieldDefinition> {
+  if let Some(InlineType { path }) = field.clone().inline {
+    let mut updated_base_field = base_field;
+    updated_base_field.of_type = inline_type(type_info, field, config)?;
+    let resolver = Lambda::context_path(path.clone());
+    updated_base_field = updated_base_field.resolver_or_default(resolver, |r| r.to_input_path(path.clone()));
+    return Valid::Ok(updated_base_field);
   }
   Valid::Ok(base_field)
 }
-fn to_args(field: &config::Field) -> Valid<Vec<InputFieldDefinition>> {
+fn to_args(field: &config::Field, config: &Config) -> Valid<Vec<InputFieldDefinition>> {
   // TODO! assert type name
   field.args.iter().validate_all(|(name, arg)| {
+    validate_enum_default(arg, config).trace(name)?;
     Valid::Ok(InputFieldDefinition {
       name: name.clone(),
       description: arg.doc.clone(),
@@ -602,6 +1135,26 @@ fn to_args(field: &config::Field) -> Valid<Vec<InputFieldDefinition>> {
     })
   })
 }
+fn validate_enum_default(arg: &Arg, config: &Config) -> Valid<()> {
+  let variants = config.find_type(&arg.type_of).and_then(|type_| type_.variants.as_ref());
+  match (variants, arg.default_value.as_ref()) {
+    (Some(variants), Some(default_value)) => {
+      let values = match default_value {
+        serde_json::Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+      };
+      values
+        .into_iter()
+        .filter(|value| !value.is_null())
+        .validate_all(|value| match value.as_str() {
+          Some(variant) if variants.contains(variant) => Valid::Ok(()),
+          _ => Valid::fail(format!("{} is not a variant of enum '{}'", value, arg.type_of)),
+        })?;
+      Valid::Ok(())
+    }
+    _ => Valid::Ok(()),
+  }
+}
 pub fn to_json_schema_for_field(field: &Field, config: &Config) -> JsonSchema {
   to_json_schema(&field.type_of, field.required, field.list, config)
 }
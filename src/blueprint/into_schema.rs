@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use async_graphql::dynamic::{
@@ -7,6 +8,7 @@ use async_graphql::dynamic::{
 use async_graphql_value::ConstValue;
 
 use crate::blueprint::{Blueprint, Definition, Type};
+use crate::config::to_enum_value;
 use crate::http::RequestContext;
 use crate::lambda::EvaluationContext;
 
@@ -30,7 +32,7 @@ fn to_type_ref(type_of: &Type) -> dynamic::TypeRef {
   }
 }
 
-fn to_type(def: &Definition) -> dynamic::Type {
+fn to_type(def: &Definition, enums: &HashSet<&str>) -> dynamic::Type {
   match def {
     Definition::ObjectTypeDefinition(def) => {
       let mut object = dynamic::Object::new(def.name.clone());
@@ -64,8 +66,22 @@ fn to_type(def: &Definition) -> dynamic::Type {
           dyn_schema_field = dyn_schema_field.description(description);
         }
         for arg in field.args.iter() {
-          dyn_schema_field =
-            dyn_schema_field.argument(dynamic::InputValue::new(arg.name.clone(), to_type_ref(&arg.of_type)));
+          let mut input_value = dynamic::InputValue::new(arg.name.clone(), to_type_ref(&arg.of_type));
+          if let Some(default_value) = arg
+            .default_value
+            .clone()
+            .and_then(|value| ConstValue::from_json(value).ok())
+            .map(|value| {
+              if enums.contains(arg.of_type.name()) {
+                to_enum_value(value)
+              } else {
+                value
+              }
+            })
+          {
+            input_value = input_value.default_value(default_value);
+          }
+          dyn_schema_field = dyn_schema_field.argument(input_value);
         }
         object = object.field(dyn_schema_field);
       }
@@ -126,8 +142,14 @@ fn create(blueprint: &Blueprint) -> SchemaBuilder {
   let mutation = blueprint.mutation();
   let mut schema = dynamic::Schema::build(query.as_str(), mutation.as_deref(), None);
 
+  let enums: HashSet<&str> = blueprint
+    .definitions
+    .iter()
+    .filter(|def| matches!(def, Definition::EnumTypeDefinition(_)))
+    .map(|def| def.name())
+    .collect();
   for def in blueprint.definitions.iter() {
-    schema = schema.register(to_type(def));
+    schema = schema.register(to_type(def, &enums));
   }
 
   schema
@@ -9,5 +9,6 @@ mod server;
 pub mod transform;
 
 pub use blueprint::*;
+pub(crate) use from_config::inline_type;
 pub use server::*;
 pub use timeout::GlobalTimeout;
@@ -0,0 +1,45 @@
+use std::collections::BTreeSet;
+
+use crate::config::{Http, Upstream};
+
+/// Headers forwarded by a single `@http` field: the ones allowed on `@upstream` plus the field's own.
+pub fn allowed_headers(upstream: &Upstream, http: &Http) -> BTreeSet<String> {
+  let mut headers = upstream.get_allowed_headers();
+  headers.extend(http.allowed_headers.iter().cloned());
+  headers
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_global_and_field_allowed_headers() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com", allowedHeaders: ["Authorization"]) {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1", allowedHeaders: ["X-Request-Id", "authorization"])
+        posts: [User] @http(path: "/posts")
+      }
+
+      type User {
+        id: Int
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let allowed = |name: &str| {
+      let field = config.find_type("Query").unwrap().fields.get(name).unwrap();
+      config
+        .allowed_headers(field.http.as_ref().unwrap())
+        .into_iter()
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(allowed("user"), vec!["authorization", "x-request-id"]);
+    assert_eq!(allowed("posts"), vec!["authorization"]);
+  }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeSet;
+
+use serde_json::{json, Value};
+
+use crate::config::{Config, Field, Type};
+
+// Avro only allows a named type to be defined once per schema, later uses refer to it by name.
+struct AvroBuilder<'a> {
+  config: &'a Config,
+  defined: BTreeSet<String>,
+}
+
+impl<'a> AvroBuilder<'a> {
+  fn named_type(&mut self, name: &str) -> Value {
+    if self.defined.contains(name) {
+      return json!(name);
+    }
+    if let Some(union) = self.config.find_union(name) {
+      return Value::Array(union.types.iter().map(|member| self.named_type(member)).collect());
+    }
+    match self.config.find_type(name) {
+      Some(type_) if type_.scalar => json!("string"),
+      Some(type_) => {
+        self.defined.insert(name.to_string());
+        match type_.variants.as_ref() {
+          Some(variants) => json!({"type": "enum", "name": name, "symbols": variants}),
+          None => self.record(name, type_),
+        }
+      }
+      None => json!(name),
+    }
+  }
+
+  fn scalar_or_named(&mut self, type_of: &str) -> Value {
+    match type_of {
+      "Int" => json!("int"),
+      "Float" => json!("double"),
+      "String" | "ID" | "JSON" => json!("string"),
+      "Boolean" => json!("boolean"),
+      name => self.named_type(name),
+    }
+  }
+
+  fn field(&mut self, name: &str, field: &Field) -> Value {
+    let type_of = if field.list {
+      let items = self.scalar_or_named(&field.type_of);
+      json!({"type": "array", "items": nullable(items, field.list_type_required)})
+    } else {
+      self.scalar_or_named(&field.type_of)
+    };
+    if field.required {
+      json!({"name": name, "type": type_of})
+    } else {
+      json!({"name": name, "type": nullable(type_of, false), "default": null})
+    }
+  }
+
+  fn record(&mut self, name: &str, type_: &Type) -> Value {
+    let fields: Vec<Value> = type_
+      .fields
+      .iter()
+      .map(|(field_name, field)| self.field(field_name, field))
+      .collect();
+    json!({"type": "record", "name": name, "fields": fields})
+  }
+}
+
+// Nullable values are unions with `null`, which comes first so that `null` can be the field default.
+fn nullable(type_of: Value, required: bool) -> Value {
+  match type_of {
+    type_of if required => type_of,
+    Value::Array(members) => Value::Array(std::iter::once(json!("null")).chain(members).collect()),
+    type_of => json!(["null", type_of]),
+  }
+}
+
+pub fn to_avro(config: &Config, type_name: &str) -> Value {
+  match config.find_type(type_name) {
+    Some(type_) if !type_.scalar && type_.variants.is_none() => {
+      AvroBuilder { config, defined: BTreeSet::from([type_name.to_string()]) }.record(type_name, type_)
+    }
+    _ => Value::Null,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::{json, Value};
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_to_avro_nested() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      type Address {
+        city: String!
+        zip: Int
+      }
+
+      type User {
+        id: Int!
+        address: Address
+        role: Role!
+        tags: [String!]!
+        friends: [User]
+      }
+
+      type Query {
+        user: User @http(path: "/user")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+
+    let expected = json!({
+      "type": "record",
+      "name": "User",
+      "fields": [
+        {
+          "name": "address",
+          "type": ["null", {
+            "type": "record",
+            "name": "Address",
+            "fields": [
+              {"name": "city", "type": "string"},
+              {"name": "zip", "type": ["null", "int"], "default": null}
+            ]
+          }],
+          "default": null
+        },
+        {"name": "friends", "type": ["null", {"type": "array", "items": ["null", "User"]}], "default": null},
+        {"name": "id", "type": "int"},
+        {"name": "role", "type": {"type": "enum", "name": "Role", "symbols": ["ADMIN", "USER"]}},
+        {"name": "tags", "type": {"type": "array", "items": "string"}}
+      ]
+    });
+
+    assert_eq!(config.to_avro("User"), expected);
+    assert_eq!(config.to_avro("Role"), Value::Null);
+  }
+}
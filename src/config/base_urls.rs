@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use crate::config::Config;
+
+pub fn override_base_urls(config: &mut Config, map: &BTreeMap<String, String>) {
+  for type_ in config.graphql.types.values_mut() {
+    for field in type_.fields.values_mut() {
+      if let Some(base_url) = field.http.as_mut().and_then(|http| http.base_url.as_mut()) {
+        if let Some(replacement) = map.get(base_url) {
+          *base_url = replacement.clone();
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_override_one_of_two_base_urls() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        users: [User] @http(path: "/users", baseURL: "http://users.example.com")
+        posts: [Post] @http(path: "/posts", baseURL: "http://posts.example.com")
+        comments: [Post] @http(path: "/comments")
+      }
+
+      type User {
+        id: Int
+      }
+
+      type Post {
+        id: Int
+      }
+    "#;
+    let mut config = Config::from_sdl(sdl).unwrap();
+    let map = BTreeMap::from([
+      (
+        "http://users.example.com".to_string(),
+        "http://localhost:3000".to_string(),
+      ),
+      (
+        "http://unused.example.com".to_string(),
+        "http://localhost:4000".to_string(),
+      ),
+    ]);
+    config.override_base_urls(&map);
+
+    let base_url = |name: &str| {
+      config.graphql.types["Query"].fields[name]
+        .http
+        .as_ref()
+        .and_then(|http| http.base_url.clone())
+    };
+    assert_eq!(base_url("users"), Some("http://localhost:3000".to_string()));
+    assert_eq!(base_url("posts"), Some("http://posts.example.com".to_string()));
+    assert_eq!(base_url("comments"), None);
+  }
+}
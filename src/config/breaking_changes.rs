@@ -0,0 +1,295 @@
+use crate::config::{Config, Field, Type};
+use crate::valid::{Valid, ValidExtensions, VectorExtension};
+
+fn validate_field(field: &Field, baseline: &Field, is_input: bool) -> Valid<(), String> {
+  if field.type_signature() != baseline.type_signature()
+    && (field.type_of != baseline.type_of || field.list != baseline.list)
+  {
+    return Valid::fail(format!(
+      "type changed from '{}' to '{}'",
+      baseline.type_signature(),
+      field.type_signature()
+    ));
+  }
+  let narrowed = if is_input {
+    field.required && !baseline.required
+  } else {
+    !field.required && baseline.required
+  };
+  if narrowed {
+    return Valid::fail(format!(
+      "nullability changed from '{}' to '{}'",
+      baseline.type_signature(),
+      field.type_signature()
+    ));
+  }
+  let items_narrowed = if is_input {
+    field.list_type_required && !baseline.list_type_required
+  } else {
+    !field.list_type_required && baseline.list_type_required
+  };
+  if field.list && items_narrowed {
+    return Valid::fail(format!(
+      "item nullability changed from '{}' to '{}'",
+      baseline.type_signature(),
+      field.type_signature()
+    ));
+  }
+  field
+    .args
+    .iter()
+    .filter(|(name, arg)| arg.required && !baseline.args.contains_key(*name))
+    .validate_all(|(name, _)| Valid::<(), String>::fail("required argument was added".to_string()).trace(name))?;
+  baseline.args.iter().validate_all(|(name, baseline_arg)| {
+    match field.args.get(name) {
+      Some(arg) if arg.type_of != baseline_arg.type_of || arg.list != baseline_arg.list => Valid::fail(format!(
+        "type changed from '{}' to '{}'",
+        baseline_arg.type_of, arg.type_of
+      )),
+      Some(arg) if arg.required && !baseline_arg.required => {
+        Valid::fail("argument changed from optional to required".to_string())
+      }
+      Some(_) => Valid::Ok(()),
+      None => Valid::fail("argument was removed".to_string()),
+    }
+    .trace(name)
+  })?;
+  Ok(())
+}
+
+fn validate_variants(type_: &Type, baseline: &Type) -> Valid<(), String> {
+  baseline
+    .variants
+    .iter()
+    .flatten()
+    .filter(|variant| {
+      !type_
+        .variants
+        .as_ref()
+        .is_some_and(|variants| variants.contains(*variant))
+    })
+    .validate_all(|variant| Valid::<(), String>::fail("enum value was removed".to_string()).trace(variant))?;
+  Ok(())
+}
+
+pub fn assert_no_breaking_changes(config: &Config, baseline: &Config) -> Valid<(), String> {
+  let input_types = baseline.input_types();
+  baseline
+    .graphql
+    .types
+    .iter()
+    .validate_all(|(type_name, baseline_type)| {
+      match config.find_type(type_name) {
+        Some(type_) => baseline_type
+          .fields
+          .iter()
+          .validate_all(|(field_name, baseline_field)| {
+            match type_.fields.get(field_name) {
+              Some(field) => validate_field(field, baseline_field, input_types.contains(type_name)),
+              None => Valid::fail("field was removed".to_string()),
+            }
+            .trace(field_name)
+          })
+          .validate_or(validate_variants(type_, baseline_type))
+          .map(|_| ()),
+        None => Valid::fail("type was removed".to_string()),
+      }
+      .trace(type_name)
+    })?;
+  baseline.graphql.unions.keys().validate_all(|union_name| {
+    if config.graphql.unions.contains_key(union_name) {
+      Valid::Ok(())
+    } else {
+      Valid::<(), String>::fail("union was removed".to_string()).trace(union_name)
+    }
+  })?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+  use crate::valid::{Valid, ValidExtensions};
+
+  const BASELINE: &str = r#"
+    schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+      query: Query
+    }
+
+    type Query {
+      users: [User] @http(path: "/users")
+    }
+
+    type User {
+      id: Int!
+      name: String
+      email: String
+    }
+  "#;
+
+  #[test]
+  fn test_removed_field_is_breaking() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        users: [User] @http(path: "/users")
+      }
+
+      type User {
+        id: Int
+        name: String
+      }
+    "#;
+    let baseline = Config::from_sdl(BASELINE).unwrap();
+    let config = Config::from_sdl(sdl).unwrap();
+    let actual = config.assert_no_breaking_changes(&baseline);
+    let expected = Valid::<(), String>::fail("field was removed".to_string())
+      .trace("email")
+      .trace("User")
+      .validate_or(
+        Valid::<(), String>::fail("nullability changed from 'Int!' to 'Int'".to_string())
+          .trace("id")
+          .trace("User"),
+      );
+    assert_eq!(actual, expected);
+  }
+
+  #[test]
+  fn test_added_field_is_safe() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        users: [User] @http(path: "/users")
+        user(id: Int): User @http(path: "/users/1")
+      }
+
+      type User {
+        id: Int!
+        name: String
+        email: String
+        phone: String
+      }
+    "#;
+    let baseline = Config::from_sdl(BASELINE).unwrap();
+    let config = Config::from_sdl(sdl).unwrap();
+    assert_eq!(config.assert_no_breaking_changes(&baseline), Valid::Ok(()));
+  }
+
+  #[test]
+  fn test_nested_input_nullability() {
+    let sdl = |city: &str, zip: &str| {
+      format!(
+        r#"
+          schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {{
+            query: Query
+          }}
+
+          input AddressInput {{
+            city: {}
+            zip: {}
+          }}
+
+          input UserInput {{
+            address: AddressInput
+          }}
+
+          type Query {{
+            users(filter: UserInput): [Int] @http(path: "/users")
+          }}
+        "#,
+        city, zip
+      )
+    };
+    let baseline = Config::from_sdl(&sdl("String", "Int!")).unwrap();
+
+    let config = Config::from_sdl(&sdl("String!", "Int!")).unwrap();
+    let expected = Valid::<(), String>::fail("nullability changed from 'String' to 'String!'".to_string())
+      .trace("city")
+      .trace("AddressInput");
+    assert_eq!(config.assert_no_breaking_changes(&baseline), expected);
+
+    let config = Config::from_sdl(&sdl("String", "Int")).unwrap();
+    assert_eq!(config.assert_no_breaking_changes(&baseline), Valid::Ok(()));
+  }
+
+  #[test]
+  fn test_list_item_nullability() {
+    let sdl = |tag: &str, item: &str| {
+      format!(
+        r#"
+          schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {{
+            query: Query
+          }}
+
+          input UserInput {{
+            tags: [{}]
+          }}
+
+          type Query {{
+            users(filter: UserInput): [User] @http(path: "/users")
+          }}
+
+          type User {{
+            tags: [{}]
+          }}
+        "#,
+        tag, item
+      )
+    };
+    let baseline = Config::from_sdl(&sdl("String", "String!")).unwrap();
+
+    let config = Config::from_sdl(&sdl("String!", "String")).unwrap();
+    let expected = Valid::<(), String>::fail("item nullability changed from '[String!]' to '[String]'".to_string())
+      .trace("tags")
+      .trace("User")
+      .validate_or(
+        Valid::<(), String>::fail("item nullability changed from '[String]' to '[String!]'".to_string())
+          .trace("tags")
+          .trace("UserInput"),
+      );
+    assert_eq!(config.assert_no_breaking_changes(&baseline), expected);
+
+    let config = Config::from_sdl(&sdl("String", "String!")).unwrap();
+    assert_eq!(config.assert_no_breaking_changes(&baseline), Valid::Ok(()));
+  }
+
+  #[test]
+  fn test_removed_enum_value_is_breaking() {
+    let sdl = |variants: &str| {
+      format!(
+        r#"
+          schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {{
+            query: Query
+          }}
+
+          enum Role {{
+            {}
+          }}
+
+          type Query {{
+            role: Role @http(path: "/role")
+          }}
+        "#,
+        variants
+      )
+    };
+    let baseline = Config::from_sdl(&sdl("ADMIN USER")).unwrap();
+
+    let config = Config::from_sdl(&sdl("ADMIN")).unwrap();
+    let expected = Valid::<(), String>::fail("enum value was removed".to_string())
+      .trace("USER")
+      .trace("Role");
+    assert_eq!(config.assert_no_breaking_changes(&baseline), expected);
+
+    let config = Config::from_sdl(&sdl("ADMIN GUEST USER")).unwrap();
+    assert_eq!(config.assert_no_breaking_changes(&baseline), Valid::Ok(()));
+  }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use crate::config::{Config, Field};
+
+/// Every list is assumed to hold this many items when estimating the cost of its children.
+const LIST_MULTIPLIER: usize = 10;
+
+fn type_complexity<'a>(config: &'a Config, type_name: &'a str, visiting: &mut HashSet<&'a str>) -> usize {
+  let Some(type_) = config.find_type(type_name) else {
+    return 0;
+  };
+  // Recursive references are cut off where they loop back, which keeps the score finite.
+  if !visiting.insert(type_name) {
+    return 0;
+  }
+  let complexity = type_
+    .fields
+    .values()
+    .map(|field| field_complexity(config, field, visiting))
+    .fold(0, usize::saturating_add);
+  visiting.remove(type_name);
+  complexity
+}
+
+fn field_complexity<'a>(config: &'a Config, field: &'a Field, visiting: &mut HashSet<&'a str>) -> usize {
+  // An explicit `@complexity` weight replaces the static estimate of the field and everything below it.
+  if let Some(weight) = field.complexity {
+    return weight as usize;
+  }
+  let children = type_complexity(config, field.type_of.as_str(), visiting);
+  let multiplier = if field.list { LIST_MULTIPLIER } else { 1 };
+  // Deep lists or large weights saturate at `usize::MAX` instead of overflowing.
+  children.saturating_mul(multiplier).saturating_add(1)
+}
+
+pub fn estimated_complexity(config: &Config, type_name: &str, field_name: &str) -> usize {
+  let Some(field) = config
+    .find_type(type_name)
+    .and_then(|type_| type_.fields.get(field_name))
+  else {
+    return 0;
+  };
+  let mut visiting = HashSet::from([type_name]);
+  field_complexity(config, field, &mut visiting)
+}
+
+pub fn operation_count(config: &Config) -> usize {
+  let schema = &config.graphql.schema;
+  [&schema.query, &schema.mutation, &schema.subscription]
+    .into_iter()
+    .flatten()
+    .filter_map(|root| config.find_type(root))
+    .map(|type_| type_.fields.len())
+    .sum()
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_estimated_complexity() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+        posts: [Post] @http(path: "/posts")
+      }
+
+      type User {
+        id: Int
+        name: String
+        posts: [Post]
+      }
+
+      type Post {
+        id: Int
+        title: String
+        author: User
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+
+    assert_eq!(config.estimated_complexity("User", "id"), 1);
+    // Post = id + title + author, where author's User has id, name and a posts field looping back to Post.
+    assert_eq!(
+      config.estimated_complexity("Query", "posts"),
+      1 + (1 + 1 + (1 + 3)) * 10
+    );
+    // User = id + name + posts, where each Post has id, title and an author field looping back to User.
+    assert_eq!(config.estimated_complexity("Query", "user"), 1 + (1 + 1 + (1 + 3 * 10)));
+    assert!(config.estimated_complexity("Query", "posts") > config.estimated_complexity("Post", "title"));
+    assert_eq!(config.estimated_complexity("Query", "unknown"), 0);
+    assert_eq!(config.operation_count(), 2);
+  }
+
+  #[test]
+  fn test_estimated_complexity_with_weight() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        posts: [Post] @http(path: "/posts")
+      }
+
+      type Post {
+        id: Int
+        title: String
+        body: String @complexity(weight: 5)
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+
+    assert_eq!(config.estimated_complexity("Post", "body"), 5);
+    assert_eq!(config.estimated_complexity("Query", "posts"), 1 + (1 + 1 + 5) * 10);
+  }
+
+  #[test]
+  fn test_estimated_complexity_saturates() {
+    // Ten nested lists over the largest weight go well past `usize::MAX`.
+    let levels: String = (0..10)
+      .map(|i| format!("type Level{} {{ next: [Level{}] }}\n", i, i + 1))
+      .collect();
+    let sdl = format!(
+      r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {{
+        query: Query
+      }}
+
+      type Query {{
+        levels: [Level0] @http(path: "/levels")
+      }}
+
+      {}
+      type Level10 {{
+        cost: Int @complexity(weight: 4294967295)
+      }}
+    "#,
+      levels
+    );
+    let config = Config::from_sdl(&sdl).unwrap();
+
+    assert_eq!(config.estimated_complexity("Query", "levels"), usize::MAX);
+  }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use crate::config::Config;
+
+pub fn compress(config: &mut Config) {
+  let mut table = HashMap::new();
+  for type_ in config.graphql.types.values_mut() {
+    for const_field in type_.fields.values_mut().filter_map(|field| field.const_field.as_mut()) {
+      const_field.share(&mut table);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use crate::config::{Config, ConstField, Field, Type};
+
+  fn const_field(data: serde_json::Value) -> Field {
+    Field::default()
+      .type_of("String".to_string())
+      .const_field(ConstField::new(data))
+  }
+
+  #[test]
+  fn test_compress_shares_identical_const_data() {
+    let mut config = Config::default().query("Query").types(vec![
+      (
+        "Query",
+        Type::default().fields(vec![
+          ("a", const_field(json!({"name": "foo"}))),
+          ("b", const_field(json!({"name": "foo"}))),
+          ("c", const_field(json!({"name": "bar"}))),
+        ]),
+      ),
+      (
+        "User",
+        Type::default().fields(vec![("d", const_field(json!({"name": "foo"})))]),
+      ),
+    ]);
+    config.compress();
+
+    let const_of = |type_name: &str, field_name: &str| {
+      config.graphql.types[type_name].fields[field_name]
+        .const_field
+        .clone()
+        .unwrap()
+    };
+    assert!(const_of("Query", "a").shares_data_with(&const_of("Query", "b")));
+    assert!(const_of("Query", "a").shares_data_with(&const_of("User", "d")));
+    assert!(!const_of("Query", "a").shares_data_with(&const_of("Query", "c")));
+    assert_eq!(const_of("Query", "b").data(), &json!({"name": "foo"}));
+    assert_eq!(ConstField::from(json!(1)).data(), &json!(1));
+  }
+}
@@ -1,4 +1,5 @@
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
 
 use anyhow::Result;
 use async_graphql::futures_util::future::join_all;
@@ -9,12 +10,14 @@ use serde_json::Value;
 use tokio::fs::File;
 use tokio::io::AsyncReadExt;
 
-use super::{Server, Upstream};
+use super::{FieldCase, Operation, Server, Upstream, Usage};
+use crate::blueprint::Blueprint;
 use crate::config::group_by::GroupBy;
 use crate::config::source::Source;
 use crate::config::{is_default, KeyValues};
 use crate::http::Method;
 use crate::json::JsonSchema;
+use crate::mustache::Mustache;
 use crate::valid::{Valid, ValidExtensions};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default, Setters)]
@@ -31,6 +34,7 @@ impl Config {
   }
 
   pub fn output_types(&self) -> HashSet<&String> {
+    let input_types = self.input_types();
     let mut types = HashSet::new();
 
     if let Some(ref query) = &self.graphql.schema.query {
@@ -41,7 +45,10 @@ impl Config {
       types.insert(mutation);
     }
 
-    for (_, type_of) in self.graphql.types.iter() {
+    for (name, type_of) in self.graphql.types.iter() {
+      if input_types.contains(name) {
+        continue;
+      }
       if type_of.interface || !type_of.fields.is_empty() {
         for (_, field) in type_of.fields.iter() {
           types.insert(&field.type_of);
@@ -51,18 +58,25 @@ impl Config {
     types
   }
 
+  /// Types declared with `input`, plus the types reachable from an argument for configs that don't set the flag
+  /// (eg: JSON or YAML), this is the one definition of an input type for the printer and the blueprint.
   pub fn input_types(&self) -> HashSet<&String> {
-    let mut types = HashSet::new();
-    for (_, type_of) in self.graphql.types.iter() {
-      if !type_of.interface {
-        for (_, field) in type_of.fields.iter() {
-          for (_, arg) in field.args.iter() {
-            types.insert(&arg.type_of);
-          }
-        }
-      }
-    }
-    types
+    let used = super::orphan_inputs::used_input_types(self);
+    self
+      .graphql
+      .types
+      .iter()
+      .filter(|(name, type_)| type_.input || used.contains(name.as_str()))
+      .map(|(name, _)| name)
+      .collect()
+  }
+
+  pub fn input_object_types(&self) -> Vec<(&String, &Type)> {
+    super::partition::input_object_types(self)
+  }
+
+  pub fn object_types(&self) -> Vec<(&String, &Type)> {
+    super::partition::object_types(self)
   }
 
   pub fn find_type(&self, name: &str) -> Option<&Type> {
@@ -73,6 +87,24 @@ impl Config {
     self.graphql.unions.get(name)
   }
 
+  /// Checks that `path` can be walked from `type_name`, following the `type_of` of each field.
+  /// Hops past a type without a definition (eg: `JSON`) can't be checked, so they are accepted.
+  pub fn field_path_exists(&self, type_name: &str, path: &[&str]) -> bool {
+    let Some(mut type_of) = self.find_type(type_name) else {
+      return false;
+    };
+    for key in path {
+      match type_of.fields.get(*key) {
+        Some(field) => match self.find_type(&field.type_of) {
+          Some(next) => type_of = next,
+          None => return true,
+        },
+        None => return false,
+      }
+    }
+    true
+  }
+
   pub fn to_yaml(&self) -> Result<String> {
     Ok(serde_yaml::to_string(self)?)
   }
@@ -104,6 +136,27 @@ impl Config {
     self
   }
 
+  pub fn with_default_upstream(base_url: &str) -> Config {
+    Config::default()
+      .upstream(Upstream::default().base_url(Some(base_url.to_string())))
+      .query("Query")
+  }
+
+  pub fn with_type(mut self, name: &str, type_: Type) -> Self {
+    self.graphql.types.insert(name.to_string(), type_);
+    self
+  }
+
+  pub fn validate(&self) -> Valid<(), String> {
+    Blueprint::try_from(self).map(|_| ())
+  }
+
+  /// Builds an async-graphql schema backed by the config's resolvers. Executing it requires an
+  /// `Arc<RequestContext>` in the request data, just like the server does.
+  pub fn to_schema(&self) -> Valid<async_graphql::dynamic::Schema, String> {
+    Blueprint::try_from(self).map(|blueprint| blueprint.to_schema())
+  }
+
   pub fn contains(&self, name: &str) -> bool {
     self.graphql.types.contains_key(name) || self.graphql.unions.contains_key(name)
   }
@@ -112,7 +165,20 @@ impl Config {
     let server = self.server.merge_right(other.server.clone());
     let graphql = self.graphql.merge_right(other.graphql.clone());
     let upstream = self.upstream.merge_right(other.upstream.clone());
-    Self { server, upstream, graphql }
+    let mut config = Self { server, upstream, graphql };
+    config.merge_unions(other);
+    config
+  }
+
+  /// Unions defined in both configs keep the members of both, so polymorphism survives splitting a schema into files.
+  pub fn merge_unions(&mut self, other: &Self) {
+    for (name, other_union) in other.graphql.unions.iter() {
+      let union_ = match self.graphql.unions.remove(name) {
+        Some(self_union) => self_union.merge_right(other_union.clone()),
+        None => other_union.clone(),
+      };
+      self.graphql.unions.insert(name.clone(), union_);
+    }
   }
 }
 
@@ -128,6 +194,15 @@ pub struct Type {
   pub variants: Option<BTreeSet<String>>,
   #[serde(default)]
   pub scalar: bool,
+  #[serde(default)]
+  #[serde(skip_serializing_if = "is_default")]
+  pub input: bool,
+  pub model: Option<Model>,
+  #[serde(default)]
+  #[serde(skip_serializing_if = "is_default")]
+  pub tags: Vec<String>,
+  pub rename: Option<String>,
+  pub spread: Option<String>,
 }
 
 impl Type {
@@ -150,10 +225,24 @@ impl Type {
     } else {
       self.variants = other.variants.clone();
     }
+    self.model = other.model.clone().or(self.model);
+    self.rename = other.rename.clone().or(self.rename);
+    self.spread = other.spread.clone().or(self.spread);
+    self.input = self.input || other.input;
+    for tag in other.tags.iter() {
+      if !self.tags.contains(tag) {
+        self.tags.push(tag.clone());
+      }
+    }
     Self { fields, ..self.clone() }
   }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Model {
+  pub table: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct GraphQL {
   pub schema: RootSchema,
@@ -171,13 +260,6 @@ impl GraphQL {
       self.types.insert(name, other_type);
     }
 
-    for (name, mut other_union) in other.unions {
-      if let Some(self_union) = self.unions.remove(&name) {
-        other_union = self_union.merge_right(other_union);
-      }
-      self.unions.insert(name, other_union);
-    }
-
     self.schema = self.schema.merge_right(other.schema);
 
     self
@@ -224,14 +306,30 @@ pub struct Field {
   #[serde(rename = "groupBy")]
   pub group_by: Option<GroupBy>,
   pub const_field: Option<ConstField>,
+  pub sse: Option<Sse>,
+  pub expr: Option<Expr>,
+  pub validate: Option<Validation>,
+  #[serde(rename = "enableIf")]
+  pub enable_if: Option<String>,
+  #[serde(rename = "rateLimit")]
+  pub rate_limit: Option<RateLimit>,
+  pub complexity: Option<u32>,
+  pub paginate: Option<Paginate>,
+  #[serde(default)]
+  #[serde(skip_serializing_if = "is_default")]
+  pub tags: Vec<String>,
 }
 
 impl Field {
   pub fn has_resolver(&self) -> bool {
-    self.http.is_some() || self.unsafe_operation.is_some() || self.const_field.is_some()
+    self.http.is_some()
+      || self.unsafe_operation.is_some()
+      || self.const_field.is_some()
+      || self.sse.is_some()
+      || self.expr.is_some()
   }
   pub fn resolvable_directives(&self) -> Vec<&str> {
-    let mut directives = Vec::with_capacity(3);
+    let mut directives = Vec::with_capacity(5);
     if self.http.is_some() {
       directives.push("@http")
     }
@@ -241,8 +339,23 @@ impl Field {
     if self.const_field.is_some() {
       directives.push("@const")
     }
+    if self.sse.is_some() {
+      directives.push("@sse")
+    }
+    if self.expr.is_some() {
+      directives.push("@expr")
+    }
     directives
   }
+  pub fn type_signature(&self) -> String {
+    let required = |type_of: String, required: bool| if required { format!("{}!", type_of) } else { type_of };
+    let type_of = if self.list {
+      format!("[{}]", required(self.type_of.clone(), self.list_type_required))
+    } else {
+      self.type_of.clone()
+    };
+    required(type_of, self.required)
+  }
   pub fn has_batched_resolver(&self) -> bool {
     self.group_by.is_some()
   }
@@ -252,6 +365,55 @@ impl Field {
   }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EnableIf {
+  pub flag: String,
+}
+
+/// Allows at most `max` requests to a field per `window` seconds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+  pub max: u32,
+  pub window: u64,
+}
+
+/// Pages through a list field, either with a cursor connection or with offset arguments.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Paginate {
+  #[serde(rename = "type")]
+  pub type_of: PaginationType,
+  #[serde(rename = "pageSize")]
+  pub page_size: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PaginationType {
+  Cursor,
+  Offset,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Complexity {
+  pub weight: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Tag {
+  pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Rename {
+  pub to: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Spread {
+  #[serde(rename = "type")]
+  pub type_of: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Unsafe {
   pub script: String,
@@ -263,6 +425,9 @@ pub struct ModifyField {
   #[serde(default)]
   #[serde(skip_serializing_if = "is_default")]
   pub omit: bool,
+  #[serde(default)]
+  #[serde(skip_serializing_if = "is_default")]
+  pub when: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -270,7 +435,7 @@ pub struct InlineType {
   pub path: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Arg {
   pub type_of: String,
   #[serde(default)]
@@ -280,6 +445,7 @@ pub struct Arg {
   pub doc: Option<String>,
   pub modify: Option<ModifyField>,
   pub default_value: Option<Value>,
+  pub validate: Option<Validation>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -295,6 +461,15 @@ impl Union {
   }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct QueryParam {
+  pub key: String,
+  pub value: String,
+  #[serde(default)]
+  #[serde(skip_serializing_if = "is_default")]
+  pub expand: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Http {
   pub path: String,
@@ -303,7 +478,7 @@ pub struct Http {
   pub method: Method,
   #[serde(default)]
   #[serde(skip_serializing_if = "is_default")]
-  pub query: KeyValues,
+  pub query: Vec<QueryParam>,
   pub input: Option<JsonSchema>,
   pub output: Option<JsonSchema>,
   pub body: Option<String>,
@@ -312,11 +487,191 @@ pub struct Http {
   #[serde(default)]
   #[serde(skip_serializing_if = "is_default")]
   pub headers: KeyValues,
+  pub retries: Option<u32>,
+  #[serde(rename = "retryDelay")]
+  pub retry_delay: Option<u64>,
+  #[serde(rename = "retryOn")]
+  pub retry_on: Option<Vec<u16>>,
+  #[serde(rename = "withCookies")]
+  pub with_cookies: Option<bool>,
+  #[serde(rename = "assertType")]
+  pub assert_type: Option<bool>,
+  #[serde(rename = "groupBy")]
+  pub group_by: Option<Vec<String>>,
+  #[serde(rename = "skipNull")]
+  pub skip_null: Option<bool>,
+  #[serde(rename = "onError")]
+  pub on_error: Option<Value>,
+  pub accept: Option<String>,
+  #[serde(rename = "allowedHeaders")]
+  #[serde(default)]
+  #[serde(skip_serializing_if = "is_default")]
+  pub allowed_headers: BTreeSet<String>,
+  pub http2: Option<bool>,
+  #[serde(rename = "dataLoader")]
+  pub data_loader: Option<String>,
+  pub format: Option<Format>,
+  pub minify: Option<bool>,
+  pub mock: Option<Value>,
+  pub compress: Option<Compression>,
+  #[serde(rename = "queryEncoding")]
+  pub query_encoding: Option<QueryEncoding>,
+  #[serde(rename = "bodyFrom")]
+  pub body_from: Option<BodySource>,
+  #[serde(rename = "keepAlive")]
+  pub keep_alive: Option<bool>,
+  #[serde(rename = "trailingSlash")]
+  pub trailing_slash: Option<bool>,
+  pub decode: Option<Decode>,
+  #[serde(default)]
+  #[serde(skip_serializing_if = "is_default")]
+  pub middleware: Vec<String>,
+  #[serde(rename = "varyHeaders")]
+  #[serde(default)]
+  #[serde(skip_serializing_if = "is_default")]
+  pub vary_headers: Vec<String>,
+  pub jq: Option<String>,
+}
+
+impl Http {
+  pub fn get_format(&self) -> Format {
+    self.format.clone().unwrap_or_default()
+  }
+
+  pub fn get_query_encoding(&self) -> QueryEncoding {
+    self.query_encoding.clone().unwrap_or_default()
+  }
+
+  /// Names of the request headers referenced by a templated base URL, eg: `{{headers.x-tenant-url}}`.
+  pub fn base_url_headers(&self) -> Vec<String> {
+    self
+      .base_url
+      .as_ref()
+      .and_then(|base_url| Mustache::parse(base_url).ok())
+      .map(|mustache| {
+        mustache
+          .expression_segments()
+          .into_iter()
+          .filter_map(|parts| match parts.as_slice() {
+            [head, name] if head == "headers" => Some(name.clone()),
+            _ => None,
+          })
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  /// Names of the environment variables referenced by a templated base URL, eg: `{{env.PORT}}`.
+  pub fn base_url_env_vars(&self) -> Vec<String> {
+    self
+      .base_url
+      .as_ref()
+      .and_then(|base_url| Mustache::parse(base_url).ok())
+      .map(|mustache| {
+        mustache
+          .expression_segments()
+          .into_iter()
+          .filter_map(|parts| match parts.as_slice() {
+            [head, name] if head == "env" => Some(name.clone()),
+            _ => None,
+          })
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+  #[default]
+  Json,
+  Csv,
+  Text,
+  Xml,
+}
+
+/// How list values are written into the query string, eg: `a=1&a=2`, `a=1,2` or `a[]=1&a[]=2`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryEncoding {
+  #[default]
+  Repeat,
+  Comma,
+  Brackets,
+}
+
+/// Where the request body comes from when it isn't a `body` template.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BodySource {
+  Parent,
+}
+
+/// Encoding of a response that is decoded before it is assigned to the field.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Decode {
+  Base64,
+  Hex,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+  Gzip,
+  Br,
+  Deflate,
 }
 
+/// The data is kept behind an `Arc` so that `Config::compress` can share identical payloads, build it with
+/// `ConstField::new` (or `From<Value>`) and read it with `data()`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ConstField {
-  pub data: Value,
+  data: Arc<Value>,
+}
+
+impl From<Value> for ConstField {
+  fn from(data: Value) -> Self {
+    Self::new(data)
+  }
+}
+
+impl ConstField {
+  pub fn new(data: Value) -> Self {
+    Self { data: Arc::new(data) }
+  }
+  pub fn data(&self) -> &Value {
+    &self.data
+  }
+  pub fn shares_data_with(&self, other: &ConstField) -> bool {
+    Arc::ptr_eq(&self.data, &other.data)
+  }
+  pub(super) fn share(&mut self, table: &mut HashMap<String, Arc<Value>>) {
+    let key = self.data.to_string();
+    self.data = table.entry(key).or_insert_with(|| self.data.clone()).clone();
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Validation {
+  pub min_length: Option<usize>,
+  pub max_length: Option<usize>,
+  pub pattern: Option<String>,
+  pub min: Option<f64>,
+  pub max: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Expr {
+  pub body: Value,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Sse {
+  pub url: String,
+  pub event: Option<String>,
 }
 
 impl Config {
@@ -348,6 +703,148 @@ impl Config {
     super::n_plus_one::n_plus_one(self)
   }
 
+  pub fn apply_naming_convention(&mut self, case: FieldCase) {
+    super::naming_convention::apply_naming_convention(self, case)
+  }
+
+  pub fn validate_max_depth(&self, limit: usize) -> Valid<(), String> {
+    super::max_depth::validate_max_depth(self, limit)
+  }
+
+  pub fn summary(&self) -> String {
+    super::summary::summary(self)
+  }
+
+  pub fn to_markdown(&self) -> String {
+    super::markdown::to_markdown(self)
+  }
+
+  pub fn to_typescript(&self) -> String {
+    super::typescript::to_typescript(self)
+  }
+
+  pub fn to_dot(&self) -> String {
+    super::dot::to_dot(self)
+  }
+
+  /// Avro record schema for an object type, or `null` when no such object type exists.
+  pub fn to_avro(&self, type_name: &str) -> Value {
+    super::avro::to_avro(self, type_name)
+  }
+
+  pub fn to_mermaid(&self) -> String {
+    super::mermaid::to_mermaid(self)
+  }
+
+  /// Field, enum and `oneof` numbers follow the alphabetical order of the names, so they are not stable: adding or
+  /// removing a field renumbers the ones after it. Regenerated files are not wire compatible with earlier ones.
+  pub fn to_proto(&self) -> String {
+    super::proto::to_proto(self)
+  }
+
+  /// Postman collection with a request for every `@http` entry point, filled in from argument defaults.
+  pub fn to_postman(&self) -> Value {
+    super::postman::to_postman(self)
+  }
+
+  pub fn to_sql_ddl(&self) -> String {
+    super::sql_ddl::to_sql_ddl(self)
+  }
+
+  pub fn required_env_vars(&self) -> BTreeSet<String> {
+    super::env_vars::required_env_vars(self)
+  }
+
+  pub fn usages_of(&self, type_name: &str) -> Vec<Usage> {
+    super::usages::usages_of(self, type_name)
+  }
+
+  pub fn entry_points(&self) -> Vec<(Operation, String)> {
+    super::entry_points::entry_points(self)
+  }
+
+  pub fn compress(&mut self) {
+    super::compress::compress(self)
+  }
+
+  pub fn prune(&mut self, roots: &[&str]) {
+    super::prune::prune(self, roots)
+  }
+
+  /// The slice of the schema tagged with `tag`, along with the types it depends on.
+  pub fn split_by_tag(&self, tag: &str) -> Config {
+    super::split_by_tag::split_by_tag(self, tag)
+  }
+
+  pub fn estimated_complexity(&self, type_name: &str, field_name: &str) -> usize {
+    super::complexity::estimated_complexity(self, type_name, field_name)
+  }
+
+  pub fn operation_count(&self) -> usize {
+    super::complexity::operation_count(self)
+  }
+
+  pub fn input_json_schema(&self, type_name: &str) -> Option<Value> {
+    super::input_json_schema::input_json_schema(self, type_name)
+  }
+
+  pub fn override_base_urls(&mut self, map: &BTreeMap<String, String>) {
+    super::base_urls::override_base_urls(self, map)
+  }
+
+  pub fn lint_required_defaults(&self) -> Valid<(), String> {
+    super::required_defaults::lint_required_defaults(self)
+  }
+
+  pub fn validate_no_orphan_input_types(&self) -> Valid<(), String> {
+    super::orphan_inputs::validate_no_orphan_input_types(self)
+  }
+
+  pub fn check_object_cycles(&self) -> Valid<(), String> {
+    super::object_cycles::check_object_cycles(self)
+  }
+
+  pub fn assert_no_breaking_changes(&self, baseline: &Config) -> Valid<(), String> {
+    super::breaking_changes::assert_no_breaking_changes(self, baseline)
+  }
+
+  pub fn fields_without_docs(&self) -> Vec<(String, String)> {
+    super::docs_coverage::fields_without_docs(self)
+  }
+
+  pub fn normalize_descriptions(&mut self) {
+    super::descriptions::normalize_descriptions(self)
+  }
+
+  pub fn semantically_eq(&self, other: &Config) -> bool {
+    super::semantic_eq::semantically_eq(self, other)
+  }
+
+  pub fn unused_union_members(&self) -> Vec<(String, String)> {
+    super::union_members::unused_union_members(self)
+  }
+
+  pub fn interfaces(&self) -> Vec<&str> {
+    super::interfaces::interfaces(self)
+  }
+
+  pub fn implementor_map(&self) -> BTreeMap<String, Vec<String>> {
+    super::interfaces::implementor_map(self)
+  }
+
+  pub fn resolve_fragments(&mut self) -> Valid<(), String> {
+    super::fragments::resolve_fragments(self)
+  }
+
+  /// Replaces the type of every `@inline` field with the type found at the end of its path and drops the directive.
+  pub fn flatten_inline_types(&mut self) -> Valid<(), String> {
+    super::flatten::flatten_inline_types(self)
+  }
+
+  pub fn allowed_headers(&self, http: &Http) -> BTreeSet<String> {
+    super::allowed_headers::allowed_headers(&self.upstream, http)
+  }
+
   pub async fn from_file_paths(file_paths: std::slice::Iter<'_, String>) -> Result<Config> {
     let mut config = Config::default();
     let futures: Vec<_> = file_paths
@@ -372,3 +869,199 @@ impl Config {
     Ok(config)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::config::{Config, Field, Http, Paginate, PaginationType, Type};
+
+  #[test]
+  fn test_builder_config_is_valid() {
+    let config = Config::with_default_upstream("http://jsonplaceholder.typicode.com").with_type(
+      "Query",
+      Type::default().fields(vec![(
+        "greeting",
+        Field::default()
+          .type_of("String".to_string())
+          .http(Http { path: "/greeting".to_string(), ..Default::default() }),
+      )]),
+    );
+    assert_eq!(config.validate(), Ok(()));
+  }
+
+  #[test]
+  fn test_to_schema_exposes_query_fields() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user(id: Int!): User @http(path: "/users/{{args.id}}")
+        posts: [Post] @http(path: "/posts")
+      }
+
+      type User {
+        id: Int
+      }
+
+      type Post {
+        title: String
+      }
+    "#;
+    let schema = Config::from_sdl(sdl).unwrap().to_schema().unwrap();
+    let sdl = schema.sdl();
+    assert!(sdl.contains("user(id: Int!): User"));
+    assert!(sdl.contains("posts: [Post]"));
+  }
+
+  #[test]
+  fn test_input_types_without_flag() {
+    let json = r#"{
+      "server": {},
+      "upstream": {"baseURL": "http://jsonplaceholder.typicode.com"},
+      "graphql": {
+        "schema": {"query": "Query"},
+        "types": {
+          "AddressInput": {"fields": {"city": {"type_of": "String"}}},
+          "Query": {
+            "fields": {
+              "user": {"type_of": "User", "args": {"input": {"type_of": "UserInput"}}, "http": {"path": "/users"}}
+            }
+          },
+          "User": {"fields": {"id": {"type_of": "Int"}}},
+          "UserInput": {"fields": {"address": {"type_of": "AddressInput"}}}
+        },
+        "unions": {}
+      }
+    }"#;
+    let config = Config::from_json(json).unwrap();
+    let mut input_types: Vec<_> = config.input_types().into_iter().cloned().collect();
+    input_types.sort();
+    assert_eq!(input_types, vec!["AddressInput", "UserInput"]);
+
+    let sdl = config.to_sdl();
+    assert!(sdl.contains("input AddressInput {"));
+    assert!(sdl.contains("input UserInput {"));
+    let schema = config.to_schema().unwrap().sdl();
+    assert!(schema.contains("input AddressInput {"));
+    assert!(schema.contains("input UserInput {"));
+  }
+
+  #[test]
+  fn test_base_url_headers() {
+    let http = Http { base_url: Some("{{headers.x-tenant-url}}/{{env.VERSION}}".to_string()), ..Default::default() };
+    assert_eq!(http.base_url_headers(), vec!["x-tenant-url".to_string()]);
+  }
+
+  #[test]
+  fn test_base_url_env_vars() {
+    let http = Http {
+      base_url: Some("http://{{env.HOST}}:{{env.PORT}}/{{headers.x-version}}".to_string()),
+      ..Default::default()
+    };
+    assert_eq!(http.base_url_env_vars(), vec!["HOST".to_string(), "PORT".to_string()]);
+  }
+
+  #[test]
+  fn test_paginate_enum_type() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        posts: [Int] @http(path: "/posts") @paginate(type: cursor, pageSize: 20)
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let paginate = config.find_type("Query").unwrap().fields["posts"].paginate.clone();
+    assert_eq!(
+      paginate,
+      Some(Paginate { type_of: PaginationType::Cursor, page_size: 20 })
+    );
+  }
+
+  #[test]
+  fn test_field_path_exists() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+
+      type User {
+        address: Address
+      }
+
+      type Address {
+        geo: Geo
+      }
+
+      type Geo {
+        lat: String
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    assert!(config.field_path_exists("Query", &["user", "address", "geo", "lat"]));
+    assert!(!config.field_path_exists("Query", &["user", "address", "zip"]));
+    assert!(!config.field_path_exists("Unknown", &["user"]));
+  }
+
+  #[test]
+  fn test_merge_unions() {
+    let base = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      union Entity = User | Post
+
+      type Query {
+        search: [Entity] @http(path: "/search")
+      }
+
+      type User {
+        id: Int
+      }
+
+      type Post {
+        id: Int
+      }
+    "#;
+    let extension = r#"
+      schema @server @upstream {
+        query: Query
+      }
+
+      union Entity = Post | Comment
+
+      type Query {
+        search: [Entity] @http(path: "/search")
+      }
+
+      type Comment {
+        id: Int
+      }
+    "#;
+    let config = Config::from_sdl(base)
+      .unwrap()
+      .merge_right(&Config::from_sdl(extension).unwrap());
+    let members: Vec<&str> = config
+      .find_union("Entity")
+      .unwrap()
+      .types
+      .iter()
+      .map(String::as_str)
+      .collect();
+    assert_eq!(members, vec!["Comment", "Post", "User"]);
+  }
+
+  #[test]
+  fn test_builder_config_without_query_type_is_invalid() {
+    let config = Config::with_default_upstream("http://jsonplaceholder.typicode.com");
+    assert!(config.validate().is_err());
+  }
+}
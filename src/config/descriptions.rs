@@ -0,0 +1,61 @@
+use crate::config::Config;
+
+fn normalize(doc: &str) -> String {
+  let mut lines: Vec<&str> = Vec::new();
+  for line in doc.lines().map(str::trim_end) {
+    if line.is_empty() && lines.last().is_some_and(|last| last.is_empty()) {
+      continue;
+    }
+    lines.push(line);
+  }
+  lines.join("\n").trim_end().to_string()
+}
+
+fn normalize_doc(doc: &mut Option<String>) {
+  if let Some(doc) = doc.as_mut() {
+    *doc = normalize(doc);
+  }
+}
+
+pub fn normalize_descriptions(config: &mut Config) {
+  for type_ in config.graphql.types.values_mut() {
+    normalize_doc(&mut type_.doc);
+    for field in type_.fields.values_mut() {
+      normalize_doc(&mut field.doc);
+      field.args.values_mut().for_each(|arg| normalize_doc(&mut arg.doc));
+    }
+  }
+  config
+    .graphql
+    .unions
+    .values_mut()
+    .for_each(|union_| normalize_doc(&mut union_.doc));
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::{Config, Field, Type};
+
+  #[test]
+  fn test_normalize_descriptions() {
+    let messy = "A user  \n\n\n\nof the blog\t\n\n";
+    let mut config = Config::default().types(vec![(
+      "User",
+      Type { doc: Some(messy.to_string()), ..Default::default() }.fields(vec![(
+        "name",
+        Field { doc: Some("The name \n".to_string()), ..Default::default() },
+      )]),
+    )]);
+
+    config.normalize_descriptions();
+    let user = &config.graphql.types["User"];
+    assert_eq!(user.doc.as_deref(), Some("A user\n\nof the blog"));
+    assert_eq!(user.fields["name"].doc.as_deref(), Some("The name"));
+
+    let normalized = config.clone();
+    config.normalize_descriptions();
+    assert_eq!(config.graphql.types["User"].doc, normalized.graphql.types["User"].doc);
+  }
+}
@@ -0,0 +1,63 @@
+use crate::config::Config;
+
+pub fn fields_without_docs(config: &Config) -> Vec<(String, String)> {
+  config
+    .graphql
+    .types
+    .iter()
+    .flat_map(|(type_name, type_)| {
+      type_
+        .fields
+        .iter()
+        .filter(|(_, field)| field.doc.is_none())
+        .map(move |(field_name, _)| (type_name.clone(), field_name.clone()))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_fields_without_docs() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        "Finds a user"
+        user(filter: UserFilter): User @http(path: "/users/1")
+        node: Node @http(path: "/nodes/1")
+      }
+
+      interface Node {
+        "Unique identifier"
+        id: Int
+      }
+
+      input UserFilter {
+        "Name to match"
+        name: String
+        role: String
+      }
+
+      type User implements Node {
+        "Unique identifier"
+        id: Int
+        name: String
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let actual = config.fields_without_docs();
+    let expected = vec![
+      ("Query".to_string(), "node".to_string()),
+      ("User".to_string(), "name".to_string()),
+      ("UserFilter".to_string(), "role".to_string()),
+    ];
+    assert_eq!(actual, expected);
+  }
+}
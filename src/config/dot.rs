@@ -0,0 +1,88 @@
+use crate::config::Config;
+
+pub fn to_dot(config: &Config) -> String {
+  let mut lines = vec!["digraph {".to_string()];
+
+  for name in config.graphql.types.keys().chain(config.graphql.unions.keys()) {
+    lines.push(format!("  \"{}\";", name));
+  }
+
+  for (name, type_) in config.graphql.types.iter() {
+    for (field_name, field) in type_.fields.iter() {
+      if config.contains(&field.type_of) {
+        lines.push(format!(
+          "  \"{}\" -> \"{}\" [label=\"{}\"];",
+          name, field.type_of, field_name
+        ));
+      }
+    }
+    for interface in type_.implements.iter() {
+      lines.push(format!("  \"{}\" -> \"{}\" [style=dashed];", name, interface));
+    }
+  }
+
+  for (name, union_) in config.graphql.unions.iter() {
+    for member in union_.types.iter() {
+      lines.push(format!("  \"{}\" -> \"{}\" [style=dotted];", name, member));
+    }
+  }
+
+  lines.push("}".to_string());
+  lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use stripmargin::StripMargin;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_to_dot() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      union Entity = User | Post
+
+      interface Node {
+        id: Int
+      }
+
+      type User implements Node {
+        id: Int
+        posts: [Post]
+      }
+
+      type Post {
+        title: String
+        author: User
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+        search: [Entity] @http(path: "/search")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = r#"|digraph {
+                      |  "Node";
+                      |  "Post";
+                      |  "Query";
+                      |  "User";
+                      |  "Entity";
+                      |  "Post" -> "User" [label="author"];
+                      |  "Query" -> "Entity" [label="search"];
+                      |  "Query" -> "User" [label="user"];
+                      |  "User" -> "Post" [label="posts"];
+                      |  "User" -> "Node" [style=dashed];
+                      |  "Entity" -> "Post" [style=dotted];
+                      |  "Entity" -> "User" [style=dotted];
+                      |}"#
+      .strip_margin();
+
+    assert_eq!(config.to_dot(), expected);
+  }
+}
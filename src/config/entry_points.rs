@@ -0,0 +1,69 @@
+use crate::config::Config;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Operation {
+  Query,
+  Mutation,
+  Subscription,
+}
+
+pub fn entry_points(config: &Config) -> Vec<(Operation, String)> {
+  let schema = &config.graphql.schema;
+  let roots = [
+    (Operation::Query, &schema.query),
+    (Operation::Mutation, &schema.mutation),
+    (Operation::Subscription, &schema.subscription),
+  ];
+  let mut entry_points = Vec::new();
+  for (operation, root) in roots {
+    let root_type = root.as_ref().and_then(|name| config.find_type(name));
+    for (name, field) in root_type.iter().flat_map(|type_| type_.fields.iter()) {
+      if field.has_resolver() {
+        entry_points.push((operation, name.clone()));
+      }
+    }
+  }
+  entry_points
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::Operation;
+  use crate::config::Config;
+
+  #[test]
+  fn test_entry_points() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+        mutation: Mutation
+      }
+
+      type Query {
+        users: [User] @http(path: "/users")
+        version: String @const(data: "1.0")
+        unresolved: String
+      }
+
+      type Mutation {
+        createUser(name: String): User @http(path: "/users", method: POST)
+      }
+
+      type User {
+        id: Int
+        posts: [String] @http(path: "/users/{{value.id}}/posts")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+
+    let actual = config.entry_points();
+    let expected = vec![
+      (Operation::Query, "users".to_string()),
+      (Operation::Query, "version".to_string()),
+      (Operation::Mutation, "createUser".to_string()),
+    ];
+    assert_eq!(actual, expected);
+  }
+}
@@ -0,0 +1,115 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use crate::config::{Config, Field, Http};
+use crate::mustache::Mustache;
+
+fn json_templates<'a>(value: &'a Value, templates: &mut Vec<&'a String>) {
+  match value {
+    Value::String(template) => templates.push(template),
+    Value::Array(items) => items.iter().for_each(|item| json_templates(item, templates)),
+    Value::Object(map) => map.values().for_each(|item| json_templates(item, templates)),
+    _ => {}
+  }
+}
+
+fn templates(field: &Field) -> Vec<&String> {
+  let mut templates = Vec::new();
+  if let Some(http) = field.http.as_ref() {
+    templates.extend(http_templates(http));
+  }
+  if let Some(expr) = field.expr.as_ref() {
+    json_templates(&expr.body, &mut templates);
+  }
+  templates
+}
+
+fn http_templates(http: &Http) -> Vec<&String> {
+  let mut templates = vec![&http.path];
+  templates.extend(http.base_url.iter());
+  templates.extend(http.body.iter());
+  templates.extend(http.query.iter().map(|param| &param.value));
+  templates.extend(http.headers.values());
+  templates
+}
+
+fn env_names(template: &str, names: &mut BTreeSet<String>) {
+  if let Ok(mustache) = Mustache::parse(template) {
+    for parts in mustache.expression_segments() {
+      if let [head, name, ..] = parts.as_slice() {
+        if head == "env" {
+          names.insert(name.clone());
+        }
+      }
+    }
+  }
+}
+
+pub fn required_env_vars(config: &Config) -> BTreeSet<String> {
+  let mut names = BTreeSet::new();
+  config
+    .upstream
+    .base_url
+    .iter()
+    .for_each(|url| env_names(url, &mut names));
+  for type_ in config.graphql.types.values() {
+    for field in type_.fields.values() {
+      templates(field)
+        .into_iter()
+        .for_each(|template| env_names(template, &mut names));
+    }
+  }
+  names
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use serde_json::json;
+
+  use crate::config::{Config, Expr, Field, Http, KeyValues, QueryParam, Type, Upstream};
+
+  #[test]
+  fn test_required_env_vars() {
+    let http = Http {
+      path: "/users/{{env.USER_ID}}".to_string(),
+      base_url: Some("{{env.API_HOST}}".to_string()),
+      body: Some("{{env.BODY}}".to_string()),
+      query: vec![QueryParam { key: "key".to_string(), value: "{{env.API_KEY}}".to_string(), expand: false }],
+      headers: KeyValues(BTreeMap::from([(
+        "authorization".to_string(),
+        "Bearer {{env.TOKEN}} {{headers.x}}".to_string(),
+      )])),
+      ..Default::default()
+    };
+    let config = Config::default()
+      .upstream(Upstream::default().base_url(Some("http://{{env.UPSTREAM}}".to_string())))
+      .query("Query")
+      .types(vec![(
+        "Query",
+        Type::default().fields(vec![
+          ("user", Field::default().type_of("User".to_string()).http(http)),
+          (
+            "posts",
+            Field::default()
+              .type_of("Post".to_string())
+              .http(Http { path: "/posts".to_string(), ..Default::default() }),
+          ),
+          (
+            "greeting",
+            Field::default()
+              .type_of("Greeting".to_string())
+              .expr(Expr { body: json!({"text": ["{{env.GREETING}}"]}) }),
+          ),
+        ]),
+      )]);
+
+    let actual: Vec<_> = config.required_env_vars().into_iter().collect();
+    let expected = vec![
+      "API_HOST", "API_KEY", "BODY", "GREETING", "TOKEN", "UPSTREAM", "USER_ID",
+    ];
+    assert_eq!(actual, expected);
+  }
+}
@@ -0,0 +1,179 @@
+use crate::blueprint::{inline_type, Type as OfType};
+use crate::config::Config;
+use crate::valid::{Valid, ValidExtensions, VectorExtension};
+
+pub fn flatten_inline_types(config: &mut Config) -> Valid<(), String> {
+  let resolved = config.graphql.types.iter().validate_all(|(type_name, type_)| {
+    type_
+      .fields
+      .iter()
+      .filter(|(_, field)| field.inline.is_some())
+      .validate_all(|(field_name, field)| {
+        inline_type(type_, field, config)
+          .map(|of_type| (type_name.clone(), field_name.clone(), of_type))
+          .trace("@inline")
+          .trace(field_name)
+      })
+      .trace(type_name)
+  })?;
+
+  for (type_name, field_name, of_type) in resolved.into_iter().flatten() {
+    if let Some(field) = config
+      .graphql
+      .types
+      .get_mut(&type_name)
+      .and_then(|type_| type_.fields.get_mut(&field_name))
+    {
+      field.type_of = of_type.name().to_string();
+      field.required = !of_type.is_nullable();
+      match of_type {
+        OfType::ListType { of_type, .. } => {
+          field.list = true;
+          field.list_type_required = !of_type.is_nullable();
+        }
+        OfType::NamedType { .. } => {
+          field.list = false;
+          field.list_type_required = false;
+        }
+      }
+      field.inline = None;
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+  use crate::valid::{Valid, ValidExtensions};
+
+  #[test]
+  fn test_flatten_two_hop_inline() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+
+      type User {
+        name: String
+        address: Address
+        city: Address @inline(path: ["address", "city"])
+      }
+
+      type Address {
+        city: String
+        geo: Geo
+      }
+
+      type Geo {
+        lat: String
+      }
+    "#;
+    let mut config = Config::from_sdl(sdl).unwrap();
+    config.flatten_inline_types().unwrap();
+
+    let city = config.find_type("User").unwrap().fields.get("city").unwrap();
+    assert_eq!(city.type_of, "String");
+    assert!(city.inline.is_none());
+  }
+
+  #[test]
+  fn test_flatten_broken_path() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+
+      type User {
+        address: Address
+        zip: Address @inline(path: ["address", "zip"])
+      }
+
+      type Address {
+        city: String
+      }
+    "#;
+    let mut config = Config::from_sdl(sdl).unwrap();
+    let expected = Valid::<(), String>::fail("Inline can't be done because provided path doesn't exist".to_string())
+      .trace("@inline")
+      .trace("zip")
+      .trace("User");
+    assert_eq!(config.flatten_inline_types(), expected);
+  }
+
+  #[test]
+  fn test_flatten_nullability() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+
+      type User {
+        address: Address!
+        city: String! @inline(path: ["address", "city"])
+        tags: [String!]! @inline(path: ["address", "tags"])
+      }
+
+      type Address {
+        city: String!
+        tags: [String!]!
+      }
+    "#;
+    let mut config = Config::from_sdl(sdl).unwrap();
+    config.flatten_inline_types().unwrap();
+
+    // the blueprint exposes inlined fields as nullable, whatever the fields along the path declare
+    let user = config.find_type("User").unwrap();
+    let city = user.fields.get("city").unwrap();
+    assert_eq!(
+      (city.type_of.as_str(), city.required, city.list),
+      ("String", false, false)
+    );
+    let tags = user.fields.get("tags").unwrap();
+    assert_eq!((tags.required, tags.list, tags.list_type_required), (false, true, true));
+  }
+
+  #[test]
+  fn test_flatten_through_resolver() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+
+      type User {
+        id: Int
+        address: Address @http(path: "/users/{{value.id}}/address")
+        city: String @inline(path: ["address", "city"])
+      }
+
+      type Address {
+        city: String
+      }
+    "#;
+    let mut config = Config::from_sdl(sdl).unwrap();
+    let expected =
+      Valid::<(), String>::fail("Inline can't be done because of http resolver at [String.address]".to_string())
+        .trace("@inline")
+        .trace("city")
+        .trace("User");
+    assert_eq!(config.flatten_inline_types(), expected);
+  }
+}
@@ -0,0 +1,110 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::config::{Config, Field};
+use crate::valid::{Valid, ValidExtensions, VectorExtension};
+
+// Spreads are followed transitively, stopping at types that were already visited so cycles terminate.
+fn spread_fields(config: &Config, name: &str, visiting: &mut HashSet<String>) -> BTreeMap<String, Field> {
+  let mut fields = BTreeMap::new();
+  if !visiting.insert(name.to_string()) {
+    return fields;
+  }
+  if let Some(type_) = config.find_type(name) {
+    fields.extend(type_.fields.clone());
+    if let Some(spread) = type_.spread.as_ref() {
+      for (field_name, field) in spread_fields(config, spread, visiting) {
+        fields.entry(field_name).or_insert(field);
+      }
+    }
+  }
+  fields
+}
+
+pub fn resolve_fragments(config: &mut Config) -> Valid<(), String> {
+  let spreads: Vec<(String, String)> = config
+    .graphql
+    .types
+    .iter()
+    .filter_map(|(name, type_)| type_.spread.clone().map(|spread| (name.clone(), spread)))
+    .collect();
+
+  spreads.iter().validate_all(|(name, spread)| {
+    if config.find_type(spread).is_some() {
+      Valid::Ok(())
+    } else {
+      Valid::fail(format!("Cannot spread type '{}' because it is not defined", spread))
+        .trace("@spread")
+        .trace(name)
+    }
+  })?;
+
+  for (name, _) in spreads.iter() {
+    let fields = spread_fields(config, name, &mut HashSet::new());
+    if let Some(type_) = config.graphql.types.get_mut(name) {
+      type_.fields = fields;
+      type_.spread = None;
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+  use crate::valid::{Valid, ValidExtensions};
+
+  #[test]
+  fn test_resolve_fragments_keeps_host_fields() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Audit {
+        createdAt: String
+        id: String
+      }
+
+      type User @spread(type: "Audit") {
+        id: Int
+        name: String
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+    "#;
+    let mut config = Config::from_sdl(sdl).unwrap();
+    assert_eq!(config.resolve_fragments(), Ok(()));
+
+    let user = &config.graphql.types["User"];
+    let fields: Vec<_> = user.fields.keys().cloned().collect();
+    assert_eq!(fields, vec!["createdAt", "id", "name"]);
+    assert_eq!(user.fields["id"].type_of, "Int");
+    assert_eq!(user.spread, None);
+  }
+
+  #[test]
+  fn test_resolve_fragments_missing_type() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type User @spread(type: "Audit") {
+        id: Int
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+    "#;
+    let mut config = Config::from_sdl(sdl).unwrap();
+    let expected = Valid::fail("Cannot spread type 'Audit' because it is not defined".to_string())
+      .trace("@spread")
+      .trace("User");
+    assert_eq!(config.resolve_fragments(), expected);
+  }
+}
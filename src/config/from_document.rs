@@ -1,6 +1,7 @@
 #![allow(clippy::too_many_arguments)]
 
-use std::collections::BTreeMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 
 use async_graphql::parser::types::{
   BaseType, ConstDirective, EnumType, FieldDefinition, InputObjectType, InputValueDefinition, SchemaDefinition,
@@ -8,11 +9,15 @@ use async_graphql::parser::types::{
 };
 use async_graphql::parser::Positioned;
 use async_graphql::Name;
+use regex::Regex;
 
 use crate::config::group_by::GroupBy;
 use crate::config::{self, Config, GraphQL, Http, RootSchema, Server, Union, Upstream};
 use crate::directive::DirectiveCodec;
-use crate::valid::{Valid as ValidDefault, ValidExtensions, ValidationError};
+use crate::jq::JqFilter;
+use crate::mustache::Mustache;
+use crate::path_string::PathString;
+use crate::valid::{Valid as ValidDefault, ValidExtensions, ValidationError, VectorExtension};
 
 type Valid<A> = ValidDefault<A, String>;
 fn from_document(doc: ServiceDocument) -> Valid<Config> {
@@ -35,6 +40,10 @@ fn graphql(doc: &ServiceDocument) -> Valid<GraphQL> {
     .collect();
 
   let root_schema = to_root_schema(schema_definition(doc)?);
+  type_definitions
+    .iter()
+    .validate_all(|type_definition| validate_reserved_names(&type_definition.node))?;
+  validate_root_types(&root_schema, &to_object_kinds(&type_definitions))?;
 
   Valid::Ok(GraphQL {
     schema: root_schema,
@@ -43,7 +52,75 @@ fn graphql(doc: &ServiceDocument) -> Valid<GraphQL> {
   })
 }
 
-fn schema_definition(doc: &ServiceDocument) -> Valid<&SchemaDefinition> {
+// Maps every type name to whether its first definition is an object type.
+fn to_object_kinds(type_definitions: &[&Positioned<TypeDefinition>]) -> BTreeMap<String, bool> {
+  let mut kinds = BTreeMap::new();
+  for type_definition in type_definitions {
+    kinds
+      .entry(pos_name_to_string(&type_definition.node.name))
+      .or_insert(matches!(type_definition.node.kind, TypeKind::Object(_)));
+  }
+  kinds
+}
+pub(super) fn validate_root_types(root_schema: &RootSchema, object_kinds: &BTreeMap<String, bool>) -> Valid<()> {
+  let roots = [
+    ("Query", &root_schema.query),
+    ("Mutation", &root_schema.mutation),
+    ("Subscription", &root_schema.subscription),
+  ];
+  roots
+    .iter()
+    .filter_map(|(operation, name)| name.as_ref().map(|name| (operation, name)))
+    .validate_all(|(operation, name)| match object_kinds.get(name.as_str()) {
+      Some(true) => Valid::Ok(()),
+      Some(false) => Valid::fail(format!("{} type must be an object type", operation)).trace(name),
+      None => Valid::fail(format!("{} type is not defined", operation)).trace(name),
+    })?;
+  Valid::Ok(())
+}
+fn validate_reserved_name(name: &str) -> Valid<()> {
+  if name.starts_with("__") {
+    Valid::fail(format!(
+      "'{}' is a reserved name, names starting with '__' are reserved for introspection",
+      name
+    ))
+  } else {
+    Valid::Ok(())
+  }
+}
+pub(super) fn validate_reserved_names(type_definition: &TypeDefinition) -> Valid<()> {
+  let type_name = type_definition.name.node.as_str();
+  let inner = match &type_definition.kind {
+    TypeKind::Object(object_type) => validate_reserved_field_names(&object_type.fields),
+    TypeKind::Interface(interface_type) => validate_reserved_field_names(&interface_type.fields),
+    TypeKind::InputObject(input_object_type) => input_object_type
+      .fields
+      .iter()
+      .validate_all(|field| validate_reserved_name(field.node.name.node.as_str()))
+      .map(|_| ()),
+    TypeKind::Enum(enum_type) => enum_type
+      .values
+      .iter()
+      .validate_all(|value| validate_reserved_name(value.node.value.node.as_str()))
+      .map(|_| ()),
+    _ => Valid::Ok(()),
+  };
+  validate_reserved_name(type_name).validate_or(inner).trace(type_name)
+}
+fn validate_reserved_field_names(fields: &[Positioned<FieldDefinition>]) -> Valid<()> {
+  fields
+    .iter()
+    .validate_all(|field| {
+      let field_name = field.node.name.node.as_str();
+      let args = field.node.arguments.iter().validate_all(|arg| {
+        let arg_name = arg.node.name.node.as_str();
+        validate_reserved_name(arg_name).trace(arg_name)
+      });
+      validate_reserved_name(field_name).validate_or(args).trace(field_name)
+    })
+    .map(|_| ())
+}
+pub(super) fn schema_definition(doc: &ServiceDocument) -> Valid<&SchemaDefinition> {
   let p = doc.definitions.iter().find_map(|def| match def {
     TypeSystemDefinition::Schema(schema_definition) => Some(&schema_definition.node),
     _ => None,
@@ -67,50 +144,121 @@ fn process_schema_directives<'a, T: DirectiveCodec<'a, T> + Default>(
   res
 }
 
-fn server(schema_definition: &SchemaDefinition) -> Valid<Server> {
+pub(super) fn server(schema_definition: &SchemaDefinition) -> Valid<Server> {
   process_schema_directives(schema_definition, "server")
 }
-fn upstream(schema_definition: &SchemaDefinition) -> Valid<Upstream> {
-  process_schema_directives(schema_definition, "upstream")
-}
-fn to_root_schema(schema_definition: &SchemaDefinition) -> RootSchema {
+pub(super) fn upstream(schema_definition: &SchemaDefinition) -> Valid<Upstream> {
+  let mut upstream: Upstream = process_schema_directives(schema_definition, "upstream")?;
+  upstream.allowed_headers = upstream.allowed_headers.map(|headers| to_lowercase(&headers));
+  if let Some(base_url) = upstream.base_url.as_ref() {
+    validate_env_references(base_url).trace("baseURL").trace("@upstream")?;
+  }
+  if let Some(tls) = upstream.tls.as_ref() {
+    if tls.cert.is_some() != tls.key.is_some() {
+      return Valid::fail("cert and key must be set together".to_string())
+        .trace("tls")
+        .trace("@upstream");
+    }
+  }
+  Valid::Ok(upstream)
+}
+fn to_lowercase(headers: &BTreeSet<String>) -> BTreeSet<String> {
+  headers.iter().map(|header| header.to_lowercase()).collect()
+}
+fn validate_env_references(template: &str) -> Valid<()> {
+  let mustache = Mustache::parse(template).map_err(|e| ValidationError::new(e.to_string()))?;
+  mustache
+    .expression_segments()
+    .into_iter()
+    .validate_all(|parts| match parts.as_slice() {
+      [head, _] if head == "env" => Valid::Ok(()),
+      _ => Valid::fail(format!(
+        "only env references are allowed, found '{{{{{}}}}}'",
+        parts.join(".")
+      )),
+    })?;
+  Valid::Ok(())
+}
+pub(super) fn to_root_schema(schema_definition: &SchemaDefinition) -> RootSchema {
   let query = schema_definition.query.as_ref().map(pos_name_to_string);
   let mutation = schema_definition.mutation.as_ref().map(pos_name_to_string);
   let subscription = schema_definition.subscription.as_ref().map(pos_name_to_string);
 
   RootSchema { query, mutation, subscription }
 }
-fn pos_name_to_string(pos: &Positioned<Name>) -> String {
+pub(super) fn pos_name_to_string(pos: &Positioned<Name>) -> String {
   pos.node.to_string()
 }
 fn to_types(type_definitions: &Vec<&Positioned<TypeDefinition>>) -> Valid<BTreeMap<String, config::Type>> {
   let mut types = BTreeMap::new();
-  for type_definition in type_definitions {
+  let (extensions, definitions): (Vec<&&Positioned<TypeDefinition>>, Vec<_>) = type_definitions
+    .iter()
+    .partition(|type_definition| type_definition.node.extend);
+  for type_definition in definitions {
     let type_name = pos_name_to_string(&type_definition.node.name);
-    let type_opt = match type_definition.node.kind.clone() {
-      TypeKind::Object(object_type) => Some(to_object_type(
-        &object_type.fields,
-        &type_definition.node.description,
-        false,
-        &object_type.implements,
-      )?),
-      TypeKind::Interface(interface_type) => Some(to_object_type(
-        &interface_type.fields,
-        &type_definition.node.description,
-        true,
-        &interface_type.implements,
-      )?),
-      TypeKind::Enum(enum_type) => Some(to_enum(enum_type)),
-      TypeKind::InputObject(input_object_type) => Some(to_input_object(input_object_type)?),
-      TypeKind::Union(_) => None,
-      TypeKind::Scalar => Some(to_scalar_type()),
-    };
-    if let Some(type_) = type_opt {
+    if let Some(type_) = to_type(&type_definition.node)? {
       types.insert(type_name, type_);
     }
   }
+  merge_extensions(
+    types,
+    extensions.into_iter().map(|type_definition| {
+      (
+        pos_name_to_string(&type_definition.node.name),
+        to_type(&type_definition.node),
+      )
+    }),
+  )
+}
+pub(super) fn merge_extensions(
+  mut types: BTreeMap<String, config::Type>,
+  extensions: impl IntoIterator<Item = (String, Valid<Option<config::Type>>)>,
+) -> Valid<BTreeMap<String, config::Type>> {
+  for (type_name, extension) in extensions {
+    if let Some(extension) = extension? {
+      let type_ = types
+        .remove(&type_name)
+        .ok_or(ValidationError::new(format!(
+          "Cannot extend type '{}' because it is not defined",
+          type_name
+        )))
+        .trace(&type_name)?;
+      types.insert(type_name, type_.merge_right(&extension));
+    }
+  }
   Valid::Ok(types)
 }
+pub(super) fn to_type(type_definition: &TypeDefinition) -> Valid<Option<config::Type>> {
+  let type_opt = match type_definition.kind.clone() {
+    TypeKind::Object(object_type) => Some(to_object_type(
+      &object_type.fields,
+      &type_definition.description,
+      false,
+      &object_type.implements,
+    )?),
+    TypeKind::Interface(interface_type) => Some(to_object_type(
+      &interface_type.fields,
+      &type_definition.description,
+      true,
+      &interface_type.implements,
+    )?),
+    TypeKind::Enum(enum_type) => Some(to_enum(enum_type)),
+    TypeKind::InputObject(input_object_type) => Some(to_input_object(input_object_type)?),
+    TypeKind::Union(_) => None,
+    TypeKind::Scalar => Some(to_scalar_type()),
+  };
+  let type_name = type_definition.name.node.as_str();
+  match type_opt {
+    Some(mut type_) => {
+      type_.model = to_model(&type_definition.directives).trace(type_name)?;
+      type_.tags = to_tags(&type_definition.directives).trace(type_name)?;
+      type_.rename = to_rename(&type_definition.directives).trace(type_name)?;
+      type_.spread = to_spread(&type_definition.directives).trace(type_name)?;
+      Valid::Ok(Some(type_))
+    }
+    None => Valid::Ok(None),
+  }
+}
 fn to_scalar_type() -> config::Type {
   config::Type { scalar: true, ..Default::default() }
 }
@@ -150,7 +298,7 @@ fn to_enum(enum_type: EnumType) -> config::Type {
 }
 fn to_input_object(input_object_type: InputObjectType) -> Valid<config::Type> {
   let fields = to_input_object_fields(&input_object_type.fields)?;
-  Valid::Ok(config::Type { fields, ..Default::default() })
+  Valid::Ok(config::Type { fields, input: true, ..Default::default() })
 }
 fn to_fields_inner<T, F>(fields: &Vec<Positioned<T>>, transform: F) -> Valid<BTreeMap<String, config::Field>>
 where
@@ -178,20 +326,22 @@ fn to_field(field_definition: &FieldDefinition) -> Valid<config::Field> {
     &field_definition.ty.node,
     &field_definition.ty.node.base,
     field_definition.ty.node.nullable,
-    to_args(field_definition),
+    to_args(field_definition)?,
     &field_definition.description,
     &field_definition.directives,
   )
 }
 fn to_input_object_field(field_definition: &InputValueDefinition) -> Valid<config::Field> {
-  to_common_field(
+  let mut field = to_common_field(
     &field_definition.ty.node,
     &field_definition.ty.node.base,
     field_definition.ty.node.nullable,
     BTreeMap::new(),
     &field_definition.description,
     &field_definition.directives,
-  )
+  )?;
+  field.validate = to_validate(&field_definition.directives)?;
+  Valid::Ok(field)
 }
 fn to_common_field(
   type_: &Type,
@@ -206,11 +356,21 @@ fn to_common_field(
   let list_type_required = matches!(&base, BaseType::List(ty) if !ty.nullable);
   let doc = description.as_ref().map(|pos| pos.node.clone());
   let modify = to_modify(directives);
+  validate_omit_condition(modify.as_ref())
+    .trace("when")
+    .trace("@modify")?;
   let inline = to_inline(directives);
   let http = to_http(directives)?;
   let unsafe_operation = to_unsafe_operation(directives);
-  let group_by = to_batch(directives);
+  let group_by = to_group_by(http.as_ref(), to_batch(directives))?;
   let const_field = to_const_field(directives);
+  let sse = to_sse(directives)?;
+  let expr = to_expr(directives)?;
+  let enable_if = to_enable_if(directives)?;
+  let rate_limit = to_rate_limit(directives)?;
+  let complexity = to_complexity(directives)?;
+  let paginate = to_paginate(directives, list)?;
+  let tags = to_tags(directives)?;
   Valid::Ok(config::Field {
     type_of,
     list,
@@ -224,6 +384,14 @@ fn to_common_field(
     unsafe_operation,
     group_by,
     const_field,
+    sse,
+    expr,
+    validate: None,
+    enable_if,
+    rate_limit,
+    complexity,
+    paginate,
+    tags,
   })
 }
 fn to_unsafe_operation(directives: &[Positioned<ConstDirective>]) -> Option<config::Unsafe> {
@@ -244,18 +412,18 @@ fn to_type_of(type_: &Type) -> String {
     },
   }
 }
-fn to_args(field_definition: &FieldDefinition) -> BTreeMap<String, config::Arg> {
+fn to_args(field_definition: &FieldDefinition) -> Valid<BTreeMap<String, config::Arg>> {
   let mut args: BTreeMap<String, config::Arg> = BTreeMap::new();
 
   for arg in field_definition.arguments.iter() {
     let arg_name = pos_name_to_string(&arg.node.name);
-    let arg_val = to_arg(&arg.node);
+    let arg_val = to_arg(&arg.node)?;
     args.insert(arg_name, arg_val);
   }
 
-  args
+  Valid::Ok(args)
 }
-fn to_arg(input_value_definition: &InputValueDefinition) -> config::Arg {
+fn to_arg(input_value_definition: &InputValueDefinition) -> Valid<config::Arg> {
   let type_of = to_type_of(&input_value_definition.ty.node);
   let list = matches!(&input_value_definition.ty.node.base, BaseType::List(_));
   let required = !input_value_definition.ty.node.nullable;
@@ -267,7 +435,8 @@ fn to_arg(input_value_definition: &InputValueDefinition) -> config::Arg {
   } else {
     None
   };
-  config::Arg { type_of, list, required, doc, modify, default_value }
+  let validate = to_validate(&input_value_definition.directives)?;
+  Valid::Ok(config::Arg { type_of, list, required, doc, modify, default_value, validate })
 }
 fn to_modify(directives: &[Positioned<ConstDirective>]) -> Option<config::ModifyField> {
   directives.iter().find_map(|directive| {
@@ -278,6 +447,12 @@ fn to_modify(directives: &[Positioned<ConstDirective>]) -> Option<config::Modify
     }
   })
 }
+fn validate_omit_condition(modify: Option<&config::ModifyField>) -> Valid<()> {
+  match modify.and_then(|modify| modify.when.as_ref()) {
+    Some(when) if when.trim().is_empty() => Valid::fail("omit condition cannot be empty".to_string()),
+    _ => Valid::Ok(()),
+  }
+}
 fn to_inline(directives: &[Positioned<ConstDirective>]) -> Option<config::InlineType> {
   directives.iter().find_map(|directive| {
     if directive.node.name.node == "inline" {
@@ -290,12 +465,116 @@ fn to_inline(directives: &[Positioned<ConstDirective>]) -> Option<config::Inline
 fn to_http(directives: &[Positioned<ConstDirective>]) -> Valid<Option<config::Http>> {
   for directive in directives {
     if directive.node.name.node == "http" {
-      return Http::from_directive(&directive.node).map(Some);
+      let mut http = Http::from_directive(&directive.node)?;
+      http.allowed_headers = to_lowercase(&http.allowed_headers);
+      http.vary_headers = http.vary_headers.iter().map(|header| header.to_lowercase()).collect();
+      if http.middleware.iter().any(|name| name.trim().is_empty()) {
+        return Valid::fail("middleware name cannot be empty".to_string())
+          .trace("middleware")
+          .trace("@http");
+      }
+      if let Some(jq) = http.jq.as_ref() {
+        validate_jq(jq).trace("jq").trace("@http")?;
+      }
+      if http.body.is_some() && http.body_from.is_some() {
+        return Valid::fail("body and bodyFrom can't be used together".to_string())
+          .trace("bodyFrom")
+          .trace("@http");
+      }
+      match http.base_url.as_ref() {
+        Some(base_url) if Mustache::is_templated(base_url) => {
+          validate_header_references(base_url).trace("baseURL").trace("@http")?;
+          validate_templated_base_url(base_url).trace("baseURL").trace("@http")?;
+        }
+        Some(base_url) => validate_base_url_scheme(base_url).trace("baseURL").trace("@http")?,
+        None => {}
+      }
+      return Valid::Ok(Some(http));
     }
   }
   Valid::Ok(None)
 }
-fn to_union(union_type: UnionType, doc: &Option<String>) -> Union {
+fn validate_jq(filter: &str) -> Valid<()> {
+  JqFilter::parse(filter).map_err(ValidationError::new)?;
+  Valid::Ok(())
+}
+fn validate_header_references(template: &str) -> Valid<()> {
+  let mustache = Mustache::parse(template).map_err(|e| ValidationError::new(e.to_string()))?;
+  mustache
+    .expression_segments()
+    .into_iter()
+    .filter(|parts| parts.first().is_some_and(|head| head == "headers"))
+    .validate_all(|parts| match parts.as_slice() {
+      [_, name] if hyper::header::HeaderName::from_bytes(name.as_bytes()).is_ok() => Valid::Ok(()),
+      _ => Valid::fail(format!(
+        "'{{{{{}}}}}' is not a valid header reference, expected '{{{{headers.NAME}}}}'",
+        parts.join(".")
+      )),
+    })?;
+  Valid::Ok(())
+}
+// Stands in for every template value, so the literal parts of a templated URL can still be parsed.
+// `0` is accepted as a host, a port and a path segment alike.
+struct Placeholder;
+impl PathString for Placeholder {
+  fn path_string<T: AsRef<str>>(&self, _: &[T]) -> Option<Cow<'_, str>> {
+    Some(Cow::Borrowed("0"))
+  }
+}
+// A templated URL is only checked once its scheme is literal, eg: `http://svc:{{env.PORT}}`.
+fn validate_templated_base_url(base_url: &str) -> Valid<()> {
+  let literal_scheme = base_url.split("{{").next().is_some_and(|prefix| prefix.contains(':'));
+  if !literal_scheme {
+    return Valid::Ok(());
+  }
+  let mustache = Mustache::parse(base_url).map_err(|e| ValidationError::new(e.to_string()))?;
+  validate_base_url_scheme(&mustache.render(&Placeholder))
+}
+fn validate_base_url_scheme(base_url: &str) -> Valid<()> {
+  let url = url::Url::parse(base_url).map_err(|e| ValidationError::new(format!("Parsing failed because of {}", e)))?;
+  match url.scheme() {
+    "http" | "https" => Valid::Ok(()),
+    "unix" if url.path().trim_start_matches('/').is_empty() => {
+      Valid::fail("unix URL must include a socket path, like 'unix:///var/run/app.sock'".to_string())
+    }
+    "unix" => Valid::Ok(()),
+    scheme => Valid::fail(format!("unsupported scheme '{}', expected http, https or unix", scheme)),
+  }
+}
+fn to_model(directives: &[Positioned<ConstDirective>]) -> Valid<Option<config::Model>> {
+  for directive in directives {
+    if directive.node.name.node == "model" {
+      let model = config::Model::from_directive(&directive.node)?;
+      if model.table.trim().is_empty() {
+        return Valid::fail("table name cannot be empty".to_string()).trace("@model");
+      }
+      return Valid::Ok(Some(model));
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_rename(directives: &[Positioned<ConstDirective>]) -> Valid<Option<String>> {
+  for directive in directives {
+    if directive.node.name.node == "rename" {
+      let rename = config::Rename::from_directive(&directive.node)?;
+      if rename.to.trim().is_empty() {
+        return Valid::fail("type name cannot be empty".to_string()).trace("@rename");
+      }
+      return Valid::Ok(Some(rename.to));
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_spread(directives: &[Positioned<ConstDirective>]) -> Valid<Option<String>> {
+  for directive in directives {
+    if directive.node.name.node == "spread" {
+      let spread = config::Spread::from_directive(&directive.node)?;
+      return Valid::Ok(Some(spread.type_of));
+    }
+  }
+  Valid::Ok(None)
+}
+pub(super) fn to_union(union_type: UnionType, doc: &Option<String>) -> Union {
   let types = union_type
     .members
     .iter()
@@ -312,6 +591,15 @@ fn to_batch(directives: &[Positioned<ConstDirective>]) -> Option<GroupBy> {
     }
   })
 }
+fn to_group_by(http: Option<&Http>, group_by: Option<GroupBy>) -> Valid<Option<GroupBy>> {
+  match (http.and_then(|http| http.group_by.clone()), group_by) {
+    (Some(_), Some(_)) => Valid::fail("groupBy can't be defined both in @http and as a separate @groupBy".to_string())
+      .trace("groupBy")
+      .trace("@http"),
+    (Some(path), None) => Valid::Ok(Some(GroupBy::new(path))),
+    (None, group_by) => Valid::Ok(group_by),
+  }
+}
 fn to_const_field(directives: &[Positioned<ConstDirective>]) -> Option<config::ConstField> {
   directives.iter().find_map(|directive| {
     if directive.node.name.node == "const" {
@@ -321,6 +609,122 @@ fn to_const_field(directives: &[Positioned<ConstDirective>]) -> Option<config::C
     }
   })
 }
+fn to_sse(directives: &[Positioned<ConstDirective>]) -> Valid<Option<config::Sse>> {
+  for directive in directives {
+    if directive.node.name.node == "sse" {
+      let sse = config::Sse::from_directive(&directive.node)?;
+      if let Err(e) = url::Url::parse(&sse.url) {
+        return Valid::fail(format!("url must be absolute: {}", e))
+          .trace("url")
+          .trace("@sse");
+      }
+      return Valid::Ok(Some(sse));
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_expr(directives: &[Positioned<ConstDirective>]) -> Valid<Option<config::Expr>> {
+  for directive in directives {
+    if directive.node.name.node == "expr" {
+      return config::Expr::from_directive(&directive.node).map(Some);
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_validate(directives: &[Positioned<ConstDirective>]) -> Valid<Option<config::Validation>> {
+  for directive in directives {
+    if directive.node.name.node == "validate" {
+      let validation = config::Validation::from_directive(&directive.node)?;
+      if let Some(pattern) = validation.pattern.as_ref() {
+        if Regex::new(pattern).is_err() {
+          return Valid::fail(format!("invalid pattern '{}'", pattern))
+            .trace("pattern")
+            .trace("@validate");
+        }
+      }
+      return Valid::Ok(Some(validation));
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_enable_if(directives: &[Positioned<ConstDirective>]) -> Valid<Option<String>> {
+  for directive in directives {
+    if directive.node.name.node == "enableIf" {
+      let enable_if = config::EnableIf::from_directive(&directive.node)?;
+      if enable_if.flag.trim().is_empty() {
+        return Valid::fail("flag name cannot be empty".to_string()).trace("@enableIf");
+      }
+      return Valid::Ok(Some(enable_if.flag));
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_rate_limit(directives: &[Positioned<ConstDirective>]) -> Valid<Option<config::RateLimit>> {
+  for directive in directives {
+    if directive.node.name.node == "rateLimit" {
+      let rate_limit = config::RateLimit::from_directive(&directive.node)?;
+      if rate_limit.max == 0 {
+        return Valid::fail("max must be greater than 0".to_string())
+          .trace("max")
+          .trace("@rateLimit");
+      }
+      if rate_limit.window == 0 {
+        return Valid::fail("window must be greater than 0".to_string())
+          .trace("window")
+          .trace("@rateLimit");
+      }
+      return Valid::Ok(Some(rate_limit));
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_complexity(directives: &[Positioned<ConstDirective>]) -> Valid<Option<u32>> {
+  for directive in directives {
+    if directive.node.name.node == "complexity" {
+      let complexity = config::Complexity::from_directive(&directive.node)?;
+      if complexity.weight < 0 {
+        return Valid::fail("weight cannot be negative".to_string())
+          .trace("weight")
+          .trace("@complexity");
+      }
+      return match u32::try_from(complexity.weight) {
+        Ok(weight) => Valid::Ok(Some(weight)),
+        Err(_) => Valid::fail(format!("weight cannot be greater than {}", u32::MAX))
+          .trace("weight")
+          .trace("@complexity"),
+      };
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_paginate(directives: &[Positioned<ConstDirective>], list: bool) -> Valid<Option<config::Paginate>> {
+  for directive in directives {
+    if directive.node.name.node == "paginate" {
+      let paginate = config::Paginate::from_directive(&directive.node)?;
+      if !list {
+        return Valid::fail("only list fields can be paginated".to_string()).trace("@paginate");
+      }
+      if paginate.page_size == 0 {
+        return Valid::fail("pageSize must be greater than 0".to_string())
+          .trace("pageSize")
+          .trace("@paginate");
+      }
+      return Valid::Ok(Some(paginate));
+    }
+  }
+  Valid::Ok(None)
+}
+fn to_tags(directives: &[Positioned<ConstDirective>]) -> Valid<Vec<String>> {
+  directives
+    .iter()
+    .filter(|directive| directive.node.name.node == "tag")
+    .map(|directive| {
+      config::Tag::from_directive(&directive.node)
+        .map(|tag| tag.name)
+        .trace("@tag")
+    })
+    .collect()
+}
 
 trait HasName {
   fn name(&self) -> &Positioned<Name>;
@@ -8,6 +8,10 @@ pub struct GroupBy {
 }
 
 impl GroupBy {
+  pub fn new(path: Vec<String>) -> Self {
+    Self { path }
+  }
+
   pub fn path(&self) -> Vec<String> {
     if self.path.is_empty() {
       return vec![String::from(ID)];
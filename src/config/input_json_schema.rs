@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use serde_json::{json, Map, Value};
+
+use crate::config::{Config, Field, Type};
+
+fn scalar_schema(type_of: &str) -> Value {
+  match type_of {
+    "Int" => json!({"type": "integer"}),
+    "Float" => json!({"type": "number"}),
+    "String" | "ID" => json!({"type": "string"}),
+    "Boolean" => json!({"type": "boolean"}),
+    _ => json!({}),
+  }
+}
+
+// Schemas without a `type` (eg: custom scalars) already accept `null`.
+fn nullable(mut schema: Value) -> Value {
+  if let Some(Value::String(type_of)) = schema.get("type") {
+    schema["type"] = json!([type_of, "null"]);
+  }
+  if let Some(Value::Array(variants)) = schema.get_mut("enum") {
+    variants.push(Value::Null);
+  }
+  schema
+}
+
+struct SchemaBuilder<'a> {
+  config: &'a Config,
+  visiting: HashSet<&'a str>,
+}
+
+impl<'a> SchemaBuilder<'a> {
+  fn new(config: &'a Config) -> Self {
+    Self { config, visiting: HashSet::new() }
+  }
+
+  fn type_schema(&mut self, type_of: &'a str) -> Value {
+    match self.config.find_type(type_of) {
+      Some(type_) if type_.scalar => json!({}),
+      Some(Type { variants: Some(variants), .. }) => json!({"type": "string", "enum": variants}),
+      Some(type_) => self.object_schema(type_of, type_),
+      None => scalar_schema(type_of),
+    }
+  }
+
+  fn field_schema(&mut self, field: &'a Field) -> Value {
+    let schema = self.type_schema(&field.type_of);
+    if field.list && field.list_type_required {
+      json!({"type": "array", "items": schema})
+    } else if field.list {
+      json!({"type": "array", "items": nullable(schema)})
+    } else {
+      schema
+    }
+  }
+
+  // Recursive input types are cut off where they loop back to keep the document finite.
+  fn object_schema(&mut self, name: &'a str, type_: &'a Type) -> Value {
+    if !self.visiting.insert(name) {
+      return json!({"type": "object"});
+    }
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (field_name, field) in type_.fields.iter() {
+      properties.insert(field_name.clone(), self.field_schema(field));
+      if field.required {
+        required.push(field_name.clone());
+      }
+    }
+    self.visiting.remove(name);
+    json!({"type": "object", "properties": properties, "required": required})
+  }
+}
+
+pub fn input_json_schema(config: &Config, type_name: &str) -> Option<Value> {
+  let (name, type_) = config
+    .input_object_types()
+    .into_iter()
+    .find(|(name, _)| name.as_str() == type_name)?;
+  Some(SchemaBuilder::new(config).object_schema(name, type_))
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_input_json_schema() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+        mutation: Mutation
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+
+      type Mutation {
+        createUser(input: UserInput!): User @http(path: "/users", method: POST, body: "{{args.input}}")
+      }
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      input AddressInput {
+        city: String!
+        zip: Int
+      }
+
+      input UserInput {
+        name: String!
+        score: Float
+        role: Role
+        tags: [String!]
+        labels: [String]
+        roles: [Role]
+        address: AddressInput
+      }
+
+      type User {
+        id: Int
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = json!({
+      "type": "object",
+      "properties": {
+        "address": {
+          "type": "object",
+          "properties": {
+            "city": {"type": "string"},
+            "zip": {"type": "integer"}
+          },
+          "required": ["city"]
+        },
+        "labels": {"type": "array", "items": {"type": ["string", "null"]}},
+        "name": {"type": "string"},
+        "role": {"type": "string", "enum": ["ADMIN", "USER"]},
+        "roles": {"type": "array", "items": {"type": ["string", "null"], "enum": ["ADMIN", "USER", null]}},
+        "score": {"type": "number"},
+        "tags": {"type": "array", "items": {"type": "string"}}
+      },
+      "required": ["name"]
+    });
+
+    assert_eq!(
+      config.input_json_schema("AddressInput"),
+      Some(expected["properties"]["address"].clone())
+    );
+    assert_eq!(config.input_json_schema("UserInput"), Some(expected));
+    assert_eq!(config.input_json_schema("User"), None);
+    assert_eq!(config.input_json_schema("Unknown"), None);
+  }
+}
@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use crate::config::Config;
+
+pub fn interfaces(config: &Config) -> Vec<&str> {
+  config
+    .graphql
+    .types
+    .iter()
+    .filter(|(_, type_)| type_.interface)
+    .map(|(name, _)| name.as_str())
+    .collect()
+}
+
+pub fn implementor_map(config: &Config) -> BTreeMap<String, Vec<String>> {
+  let mut map: BTreeMap<String, Vec<String>> = interfaces(config)
+    .into_iter()
+    .map(|name| (name.to_string(), Vec::new()))
+    .collect();
+  for (name, type_) in config.graphql.types.iter() {
+    for interface in type_.implements.iter() {
+      if let Some(implementors) = map.get_mut(interface) {
+        implementors.push(name.clone());
+      }
+    }
+  }
+  map
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  fn config() -> Config {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      interface Node {
+        id: Int!
+      }
+
+      interface Named {
+        name: String
+      }
+
+      type User implements Node & Named {
+        id: Int!
+        name: String
+      }
+
+      type Post implements Node {
+        id: Int!
+      }
+
+      type Tag implements Named {
+        name: String
+      }
+
+      type Query {
+        users: [User] @http(path: "/users")
+        posts: [Post] @http(path: "/posts")
+        tags: [Tag] @http(path: "/tags")
+      }
+    "#;
+    Config::from_sdl(sdl).unwrap()
+  }
+
+  #[test]
+  fn test_interfaces() {
+    assert_eq!(config().interfaces(), vec!["Named", "Node"]);
+  }
+
+  #[test]
+  fn test_implementor_map() {
+    let expected = BTreeMap::from([
+      ("Named".to_string(), vec!["Tag".to_string(), "User".to_string()]),
+      ("Node".to_string(), vec!["Post".to_string(), "User".to_string()]),
+    ]);
+
+    assert_eq!(config().implementor_map(), expected);
+  }
+}
@@ -8,6 +8,15 @@ use crate::directive::DirectiveCodec;
 fn pos<A>(a: A) -> Positioned<A> {
   Positioned::new(a, Pos::default())
 }
+/// Enum defaults are kept as JSON strings in the config and the blueprint, this turns them back into enum names.
+pub fn to_enum_value(value: ConstValue) -> ConstValue {
+  match value {
+    ConstValue::String(name) => ConstValue::Enum(Name::new(name)),
+    ConstValue::List(values) => ConstValue::List(values.into_iter().map(to_enum_value).collect()),
+    value => value,
+  }
+}
+
 fn config_document(config: &Config) -> ServiceDocument {
   let mut definitions = Vec::new();
   let schema_definition = SchemaDefinition {
@@ -26,6 +35,7 @@ fn config_document(config: &Config) -> ServiceDocument {
       .map(|name| pos(Name::new(name))),
   };
   definitions.push(TypeSystemDefinition::Schema(pos(schema_definition)));
+  let input_types = config.input_types();
   for (type_name, type_def) in config.graphql.types.iter() {
     let kind = if type_def.interface {
       TypeKind::Interface(InterfaceType {
@@ -68,7 +78,7 @@ fn config_document(config: &Config) -> ServiceDocument {
           })
           .collect(),
       })
-    } else if config.input_types().contains(type_name) {
+    } else if input_types.contains(type_name) {
       TypeKind::InputObject(InputObjectType {
         fields: type_def
           .fields
@@ -140,8 +150,23 @@ fn config_document(config: &Config) -> ServiceDocument {
                   default_value: arg
                     .default_value
                     .clone()
-                    .map(|v| pos(ConstValue::String(v.to_string()))),
-                  directives: Vec::new(),
+                    .and_then(|v| ConstValue::from_json(v).ok())
+                    .map(|v| {
+                      if config
+                        .find_type(&arg.type_of)
+                        .is_some_and(|type_| type_.variants.is_some())
+                      {
+                        to_enum_value(v)
+                      } else {
+                        v
+                      }
+                    })
+                    .map(pos),
+                  directives: arg
+                    .validate
+                    .iter()
+                    .map(|validate| pos(validate.to_directive("validate".to_string())))
+                    .collect(),
                 })
               })
               .collect::<Vec<Positioned<InputValueDefinition>>>();
@@ -162,7 +187,7 @@ fn config_document(config: &Config) -> ServiceDocument {
       extend: false,
       description: None,
       name: pos(Name::new(type_name.clone())),
-      directives: Vec::new(),
+      directives: get_type_directives(type_def),
       kind,
     })));
   }
@@ -181,13 +206,35 @@ fn config_document(config: &Config) -> ServiceDocument {
   ServiceDocument { definitions }
 }
 
+fn get_type_directives(type_def: &crate::config::Type) -> Vec<Positioned<ConstDirective>> {
+  let mut directives = Vec::new();
+  if let Some(model) = type_def.clone().model {
+    let model_dir = model.to_directive("model".to_string());
+    directives.push(pos(model_dir));
+  }
+  for name in type_def.tags.iter() {
+    let dir = crate::config::Tag { name: name.clone() }.to_directive("tag".to_string());
+    directives.push(pos(dir));
+  }
+  if let Some(to) = type_def.rename.clone() {
+    let dir = crate::config::Rename { to }.to_directive("rename".to_string());
+    directives.push(pos(dir));
+  }
+  if let Some(type_of) = type_def.spread.clone() {
+    let dir = crate::config::Spread { type_of }.to_directive("spread".to_string());
+    directives.push(pos(dir));
+  }
+  directives
+}
+
 fn get_directives(field: &crate::config::Field) -> Vec<Positioned<ConstDirective>> {
   let mut directives = Vec::new();
   if let Some(http) = field.clone().http {
     let http_dir = http.to_directive("http".to_string());
     directives.push(pos(http_dir));
   }
-  if let Some(batch) = field.clone().group_by {
+  let inline_group_by = field.http.as_ref().is_some_and(|http| http.group_by.is_some());
+  if let Some(batch) = field.clone().group_by.filter(|_| !inline_group_by) {
     let batch_dir = batch.to_directive("groupBy".to_string());
     directives.push(pos(batch_dir));
   }
@@ -199,6 +246,14 @@ fn get_directives(field: &crate::config::Field) -> Vec<Positioned<ConstDirective
     let us_dir = const_field.to_directive("const".to_string());
     directives.push(pos(us_dir));
   }
+  if let Some(sse) = field.clone().sse {
+    let sse_dir = sse.to_directive("sse".to_string());
+    directives.push(pos(sse_dir));
+  }
+  if let Some(expr) = field.clone().expr {
+    let expr_dir = expr.to_directive("expr".to_string());
+    directives.push(pos(expr_dir));
+  }
   if let Some(inline) = field.clone().inline {
     let il_dir = inline.to_directive("inline".to_string());
     directives.push(pos(il_dir));
@@ -207,6 +262,30 @@ fn get_directives(field: &crate::config::Field) -> Vec<Positioned<ConstDirective
     let dir = modify.to_directive("modify".to_string());
     directives.push(pos(dir));
   }
+  if let Some(validate) = field.clone().validate {
+    let dir = validate.to_directive("validate".to_string());
+    directives.push(pos(dir));
+  }
+  if let Some(flag) = field.clone().enable_if {
+    let dir = crate::config::EnableIf { flag }.to_directive("enableIf".to_string());
+    directives.push(pos(dir));
+  }
+  if let Some(rate_limit) = field.clone().rate_limit {
+    let dir = rate_limit.to_directive("rateLimit".to_string());
+    directives.push(pos(dir));
+  }
+  if let Some(weight) = field.complexity {
+    let dir = crate::config::Complexity { weight: weight.into() }.to_directive("complexity".to_string());
+    directives.push(pos(dir));
+  }
+  if let Some(paginate) = field.clone().paginate {
+    let dir = paginate.to_directive("paginate".to_string());
+    directives.push(pos(dir));
+  }
+  for name in field.tags.iter() {
+    let dir = crate::config::Tag { name: name.clone() }.to_directive("tag".to_string());
+    directives.push(pos(dir));
+  }
   directives
 }
 
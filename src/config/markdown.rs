@@ -0,0 +1,156 @@
+use crate::config::{Arg, Config, Field};
+
+fn required(type_of: String, required: bool) -> String {
+  if required {
+    format!("{}!", type_of)
+  } else {
+    type_of
+  }
+}
+
+fn arg_type(arg: &Arg) -> String {
+  let type_of = if arg.list {
+    format!("[{}]", arg.type_of)
+  } else {
+    arg.type_of.clone()
+  };
+  required(type_of, arg.required)
+}
+
+fn field_signature(name: &str, field: &Field) -> String {
+  let args = field
+    .args
+    .iter()
+    .map(|(name, arg)| arg_signature(name, arg))
+    .collect::<Vec<_>>();
+  let args = if args.is_empty() {
+    String::new()
+  } else {
+    format!("({})", args.join(", "))
+  };
+  format!("`{}{}`: `{}`", name, args, field.type_signature())
+}
+
+fn arg_signature(name: &str, arg: &Arg) -> String {
+  format!("{}: {}", name, arg_type(arg))
+}
+
+pub fn to_markdown(config: &Config) -> String {
+  let mut sections = Vec::new();
+
+  for (name, type_) in config.graphql.types.iter() {
+    let mut lines = vec![format!("## {}", name)];
+    if let Some(doc) = type_.doc.as_ref() {
+      lines.push(String::new());
+      lines.push(doc.trim().to_string());
+    }
+    if let Some(variants) = type_.variants.as_ref() {
+      lines.push(String::new());
+      lines.extend(variants.iter().map(|variant| format!("- `{}`", variant)));
+    } else if !type_.fields.is_empty() {
+      lines.push(String::new());
+      lines.extend(type_.fields.iter().map(|(name, field)| {
+        let signature = field_signature(name, field);
+        match field.doc.as_ref() {
+          Some(doc) => format!("- {} — {}", signature, doc.trim()),
+          None => format!("- {}", signature),
+        }
+      }));
+    }
+    sections.push(lines.join("\n"));
+  }
+
+  for (name, union) in config.graphql.unions.iter() {
+    let mut lines = vec![format!("## {}", name)];
+    if let Some(doc) = union.doc.as_ref() {
+      lines.push(String::new());
+      lines.push(doc.trim().to_string());
+    }
+    lines.push(String::new());
+    lines.push(format!(
+      "One of: {}",
+      union
+        .types
+        .iter()
+        .map(|type_| format!("`{}`", type_))
+        .collect::<Vec<_>>()
+        .join(", ")
+    ));
+    sections.push(lines.join("\n"));
+  }
+
+  sections.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use stripmargin::StripMargin;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_to_markdown() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      union Entity = User | Post
+
+      "A user of the blog"
+      type User {
+        "Unique identifier"
+        id: Int!
+        name: String
+        role: Role
+      }
+
+      type Post {
+        id: Int!
+        title: String
+      }
+
+      type Query {
+        "Finds a user by id"
+        user(id: Int!): User @http(path: "/users/{{args.id}}")
+        posts: [Post!]! @http(path: "/posts")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = r#"|## Post
+                      |
+                      |- `id`: `Int!`
+                      |- `title`: `String`
+                      |
+                      |## Query
+                      |
+                      |- `posts`: `[Post!]!`
+                      |- `user(id: Int!)`: `User` — Finds a user by id
+                      |
+                      |## Role
+                      |
+                      |- `ADMIN`
+                      |- `USER`
+                      |
+                      |## User
+                      |
+                      |A user of the blog
+                      |
+                      |- `id`: `Int!` — Unique identifier
+                      |- `name`: `String`
+                      |- `role`: `Role`
+                      |
+                      |## Entity
+                      |
+                      |One of: `Post`, `User`"#
+      .strip_margin();
+
+    assert_eq!(config.to_markdown(), expected);
+  }
+}
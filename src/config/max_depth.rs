@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use crate::config::Config;
+use crate::valid::{Valid, ValidExtensions, VectorExtension};
+
+struct DepthFinder<'a> {
+  config: &'a Config,
+  // Types on the current path, with their position on it.
+  visiting: HashMap<&'a str, usize>,
+  depths: HashMap<&'a str, usize>,
+}
+
+impl<'a> DepthFinder<'a> {
+  fn new(config: &'a Config) -> Self {
+    Self { config, visiting: HashMap::new(), depths: HashMap::new() }
+  }
+
+  fn depth(&mut self, type_name: &'a str) -> usize {
+    self.depth_on_path(type_name).0
+  }
+
+  // Recursive references are cut off at the point where they loop back, so they don't add to the depth.
+  // Along with the depth, this returns the lowest position on the path that a loop went back to. A depth that was
+  // cut short by a loop into an ancestor only holds on this path, so it isn't memoized.
+  fn depth_on_path(&mut self, type_name: &'a str) -> (usize, usize) {
+    if let Some(depth) = self.depths.get(type_name) {
+      return (*depth, usize::MAX);
+    }
+    let Some(type_) = self.config.find_type(type_name) else {
+      return (0, usize::MAX);
+    };
+    if type_.fields.is_empty() {
+      return (0, usize::MAX);
+    }
+    if let Some(position) = self.visiting.get(type_name) {
+      return (0, *position);
+    }
+    let position = self.visiting.len();
+    self.visiting.insert(type_name, position);
+    let (depth, lowest) = type_
+      .fields
+      .values()
+      .map(|field| self.depth_on_path(field.type_of.as_str()))
+      .fold((0, usize::MAX), |(depth, lowest), (next, next_lowest)| {
+        (depth.max(next), lowest.min(next_lowest))
+      });
+    self.visiting.remove(type_name);
+    let depth = 1 + depth;
+    if lowest >= position {
+      self.depths.insert(type_name, depth);
+    }
+    (depth, lowest)
+  }
+}
+
+pub fn validate_max_depth(config: &Config, limit: usize) -> Valid<(), String> {
+  let schema = &config.graphql.schema;
+  let roots = [&schema.query, &schema.mutation, &schema.subscription];
+  let mut finder = DepthFinder::new(config);
+  let depths: Vec<_> = roots
+    .into_iter()
+    .flatten()
+    .map(|root| (root, finder.depth(root)))
+    .collect();
+
+  depths.into_iter().validate_all(|(root, depth)| {
+    if depth > limit {
+      Valid::fail(format!("type depth {} exceeds the limit of {}", depth, limit)).trace(root)
+    } else {
+      Valid::Ok(())
+    }
+  })?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::config::{Config, Field, Type};
+  use crate::valid::{Valid, ValidExtensions};
+
+  fn config() -> Config {
+    Config::default().query("Query").types(vec![
+      (
+        "Query",
+        Type::default().fields(vec![("user", Field::default().type_of("User".to_string()))]),
+      ),
+      (
+        "User",
+        Type::default().fields(vec![
+          ("name", Field::default().type_of("String".to_string())),
+          ("posts", Field::default().type_of("Post".to_string()).to_list()),
+        ]),
+      ),
+      (
+        "Post",
+        Type::default().fields(vec![
+          ("title", Field::default().type_of("String".to_string())),
+          ("author", Field::default().type_of("User".to_string())),
+        ]),
+      ),
+    ])
+  }
+
+  #[test]
+  fn test_max_depth_within_limit() {
+    assert_eq!(config().validate_max_depth(3), Ok(()));
+  }
+
+  #[test]
+  fn test_max_depth_exceeds_limit() {
+    let actual = config().validate_max_depth(2);
+    let expected = Valid::fail("type depth 3 exceeds the limit of 2".to_string()).trace("Query");
+    assert_eq!(actual, expected);
+  }
+
+  #[test]
+  fn test_max_depth_two_paths_into_a_cycle() {
+    // Reached through `x`, B loops back to A and looks one level deep. Reached through `y`, it goes through A to C.
+    let config = Config::default().query("Query").types(vec![
+      (
+        "Query",
+        Type::default().fields(vec![
+          ("x", Field::default().type_of("A".to_string())),
+          ("y", Field::default().type_of("B".to_string())),
+        ]),
+      ),
+      (
+        "A",
+        Type::default().fields(vec![
+          ("deep", Field::default().type_of("C".to_string())),
+          ("next", Field::default().type_of("B".to_string())),
+        ]),
+      ),
+      (
+        "B",
+        Type::default().fields(vec![("next", Field::default().type_of("A".to_string()))]),
+      ),
+      (
+        "C",
+        Type::default().fields(vec![("d", Field::default().type_of("D".to_string()))]),
+      ),
+      (
+        "D",
+        Type::default().fields(vec![("name", Field::default().type_of("String".to_string()))]),
+      ),
+    ]);
+    let expected = Valid::fail("type depth 5 exceeds the limit of 4".to_string()).trace("Query");
+    assert_eq!(config.validate_max_depth(4), expected);
+  }
+}
@@ -0,0 +1,127 @@
+use crate::config::Config;
+
+pub fn to_mermaid(config: &Config) -> String {
+  let mut lines = vec!["classDiagram".to_string()];
+
+  for (name, type_) in config.graphql.types.iter() {
+    lines.push(format!("  class {} {{", name));
+    if type_.interface {
+      lines.push("    <<interface>>".to_string());
+    }
+    if let Some(variants) = type_.variants.as_ref() {
+      lines.push("    <<enumeration>>".to_string());
+      lines.extend(variants.iter().map(|variant| format!("    {}", variant)));
+    }
+    for (field_name, field) in type_.fields.iter() {
+      lines.push(format!("    +{} {}", field.type_signature(), field_name));
+    }
+    lines.push("  }".to_string());
+  }
+
+  for name in config.graphql.unions.keys() {
+    lines.push(format!("  class {} {{", name));
+    lines.push("    <<union>>".to_string());
+    lines.push("  }".to_string());
+  }
+
+  for (name, type_) in config.graphql.types.iter() {
+    for (field_name, field) in type_.fields.iter() {
+      if config.contains(&field.type_of) {
+        lines.push(format!("  {} --> {} : {}", name, field.type_of, field_name));
+      }
+    }
+    for interface in type_.implements.iter() {
+      lines.push(format!("  {} ..|> {}", name, interface));
+    }
+  }
+
+  for (name, union_) in config.graphql.unions.iter() {
+    for member in union_.types.iter() {
+      lines.push(format!("  {} ..> {}", name, member));
+    }
+  }
+
+  lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use stripmargin::StripMargin;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_to_mermaid() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      union Entity = User | Post
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      interface Node {
+        id: Int!
+      }
+
+      type User implements Node {
+        id: Int!
+        role: Role
+        posts: [Post]
+      }
+
+      type Post {
+        title: String
+        author: User
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+        search: [Entity] @http(path: "/search")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = r#"|classDiagram
+                      |  class Node {
+                      |    <<interface>>
+                      |    +Int! id
+                      |  }
+                      |  class Post {
+                      |    +User author
+                      |    +String title
+                      |  }
+                      |  class Query {
+                      |    +[Entity] search
+                      |    +User user
+                      |  }
+                      |  class Role {
+                      |    <<enumeration>>
+                      |    ADMIN
+                      |    USER
+                      |  }
+                      |  class User {
+                      |    +Int! id
+                      |    +[Post] posts
+                      |    +Role role
+                      |  }
+                      |  class Entity {
+                      |    <<union>>
+                      |  }
+                      |  Post --> User : author
+                      |  Query --> Entity : search
+                      |  Query --> User : user
+                      |  User --> Post : posts
+                      |  User --> Role : role
+                      |  User ..|> Node
+                      |  Entity ..> Post
+                      |  Entity ..> User"#
+      .strip_margin();
+
+    assert_eq!(config.to_mermaid(), expected);
+  }
+}
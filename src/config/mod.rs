@@ -1,16 +1,57 @@
+mod allowed_headers;
+mod avro;
+mod base_urls;
+mod breaking_changes;
+mod complexity;
+mod compress;
 mod config;
+mod descriptions;
+mod docs_coverage;
+mod dot;
+mod entry_points;
+mod env_vars;
+mod flatten;
+mod fragments;
 mod from_document;
 pub mod group_by;
+mod input_json_schema;
+mod interfaces;
 mod into_document;
 mod key_values;
+mod markdown;
+mod max_depth;
+mod mermaid;
 mod n_plus_one;
+mod naming_convention;
+mod object_cycles;
+mod orphan_inputs;
+mod partition;
+mod postman;
+mod proto;
+mod prune;
+mod required_defaults;
+mod resolver_kind;
+mod semantic_eq;
 mod server;
 mod source;
+mod split_by_tag;
+mod sql_ddl;
+mod streaming;
+mod summary;
+mod typescript;
+mod union_members;
+mod usages;
 
 pub use config::*;
+pub use entry_points::Operation;
+pub use into_document::to_enum_value;
 pub use key_values::*;
+pub use naming_convention::FieldCase;
+pub use resolver_kind::ResolverKind;
 pub use server::*;
 pub use source::*;
+pub use streaming::from_document_streaming;
+pub use usages::Usage;
 
 fn is_default<T: Default + Eq>(val: &T) -> bool {
   *val == T::default()
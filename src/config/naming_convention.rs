@@ -0,0 +1,116 @@
+use crate::config::{Config, ModifyField};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldCase {
+  CamelCase,
+  SnakeCase,
+}
+
+impl FieldCase {
+  pub fn convert(&self, name: &str) -> String {
+    match self {
+      FieldCase::CamelCase => to_camel_case(name),
+      FieldCase::SnakeCase => to_snake_case(name),
+    }
+  }
+}
+
+fn to_camel_case(name: &str) -> String {
+  let mut parts = name.split('_').filter(|part| !part.is_empty());
+  let mut result = parts.next().map(|part| part.to_string()).unwrap_or_default();
+  for part in parts {
+    let mut chars = part.chars();
+    if let Some(first) = chars.next() {
+      result.extend(first.to_uppercase());
+      result.push_str(chars.as_str());
+    }
+  }
+  result
+}
+
+fn to_snake_case(name: &str) -> String {
+  let mut result = String::new();
+  for (i, c) in name.chars().enumerate() {
+    if c.is_uppercase() {
+      if i > 0 {
+        result.push('_');
+      }
+      result.extend(c.to_lowercase());
+    } else {
+      result.push(c);
+    }
+  }
+  result
+}
+
+/// Renames every field to the given case, keeping the original name as the
+/// upstream key by recording the new name in `@modify(name:)`.
+pub fn apply_naming_convention(config: &mut Config, case: FieldCase) {
+  for type_ in config.graphql.types.values_mut() {
+    for (name, field) in type_.fields.iter_mut() {
+      let new_name = case.convert(name);
+      match field.modify.as_mut() {
+        Some(modify) if modify.name.is_some() || modify.omit => {}
+        _ if new_name == *name => {}
+        Some(modify) => modify.name = Some(new_name),
+        None => field.modify = Some(ModifyField { name: Some(new_name), omit: false, when: None }),
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::FieldCase;
+  use crate::config::{Config, Field, ModifyField, Type};
+
+  #[test]
+  fn test_apply_naming_convention_camel_case() {
+    let mut config = Config::default().query("Query").types(vec![
+      (
+        "Query",
+        Type::default().fields(vec![("user_by_id", Field::default().type_of("User".to_string()))]),
+      ),
+      (
+        "User",
+        Type::default().fields(vec![
+          ("id", Field::default().type_of("Int".to_string())),
+          ("first_name", Field::default().type_of("String".to_string())),
+          (
+            "last_name",
+            Field::default().type_of("String".to_string()).modify(ModifyField {
+              name: Some("surname".to_string()),
+              omit: false,
+              when: None,
+            }),
+          ),
+        ]),
+      ),
+      (
+        "UserInput",
+        Type::default().fields(vec![("first_name", Field::default().type_of("String".to_string()))]),
+      ),
+    ]);
+
+    config.apply_naming_convention(FieldCase::CamelCase);
+
+    let modified_name = |type_name: &str, field_name: &str| {
+      config.find_type(type_name).unwrap().fields[field_name]
+        .modify
+        .as_ref()
+        .and_then(|modify| modify.name.clone())
+    };
+
+    assert_eq!(modified_name("Query", "user_by_id"), Some("userById".to_string()));
+    assert_eq!(modified_name("User", "id"), None);
+    assert_eq!(modified_name("User", "first_name"), Some("firstName".to_string()));
+    assert_eq!(modified_name("User", "last_name"), Some("surname".to_string()));
+    assert_eq!(modified_name("UserInput", "first_name"), Some("firstName".to_string()));
+  }
+
+  #[test]
+  fn test_field_case_snake_case() {
+    assert_eq!(FieldCase::SnakeCase.convert("firstName"), "first_name");
+    assert_eq!(FieldCase::SnakeCase.convert("id"), "id");
+  }
+}
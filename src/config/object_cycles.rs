@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use crate::config::Config;
+use crate::valid::{Valid, ValidExtensions, VectorExtension};
+
+struct CycleFinder<'a> {
+  config: &'a Config,
+  done: HashSet<&'a str>,
+  // the current chain of `Type.field` hops, used to print the cycle once it loops back
+  stack: Vec<(&'a str, &'a str)>,
+  cycles: Vec<(&'a str, String)>,
+}
+
+impl<'a> CycleFinder<'a> {
+  fn new(config: &'a Config) -> Self {
+    Self { config, done: HashSet::new(), stack: Vec::new(), cycles: Vec::new() }
+  }
+
+  // Nullable and list fields can be left empty, so only required single-object fields are followed.
+  fn visit(&mut self, type_name: &'a str) {
+    if self.done.contains(type_name) {
+      return;
+    }
+    if let Some(start) = self.stack.iter().position(|(name, _)| *name == type_name) {
+      let chain = self.stack[start..]
+        .iter()
+        .map(|(name, field)| format!("{}.{}", name, field))
+        .collect::<Vec<_>>()
+        .join(" -> ");
+      self.cycles.push((type_name, format!("{} -> {}", chain, type_name)));
+      return;
+    }
+    let Some(type_) = self.config.find_type(type_name) else {
+      return;
+    };
+    for (field_name, field) in type_.fields.iter() {
+      if field.required && !field.list {
+        self.stack.push((type_name, field_name.as_str()));
+        self.visit(field.type_of.as_str());
+        self.stack.pop();
+      }
+    }
+    self.done.insert(type_name);
+  }
+}
+
+pub fn check_object_cycles(config: &Config) -> Valid<(), String> {
+  let mut finder = CycleFinder::new(config);
+  for name in config.graphql.types.keys() {
+    finder.visit(name.as_str());
+  }
+
+  finder.cycles.into_iter().validate_all(|(type_name, chain)| {
+    Valid::<(), String>::fail(format!(
+      "required fields form a cycle, so '{}' can never be constructed: {}",
+      type_name, chain
+    ))
+    .trace(type_name)
+  })?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+  use crate::valid::{Valid, ValidExtensions};
+
+  #[test]
+  fn test_required_cycle() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+
+      type User {
+        id: Int!
+        profile: Profile!
+      }
+
+      type Profile {
+        owner: User!
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = Valid::<(), String>::fail(
+      "required fields form a cycle, so 'Profile' can never be constructed: Profile.owner -> User.profile -> Profile"
+        .to_string(),
+    )
+    .trace("Profile");
+    assert_eq!(config.check_object_cycles(), expected);
+  }
+
+  #[test]
+  fn test_nullable_cycle() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        user: User @http(path: "/users/1")
+      }
+
+      type User {
+        id: Int!
+        profile: Profile!
+        friends: [User!]!
+      }
+
+      type Profile {
+        owner: User
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    assert_eq!(config.check_object_cycles(), Valid::Ok(()));
+  }
+}
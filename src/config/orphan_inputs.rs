@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use crate::config::Config;
+use crate::valid::{Valid, ValidExtensions, VectorExtension};
+
+// Input types are only reachable through arguments, either directly or nested in another input type.
+pub(super) fn used_input_types(config: &Config) -> HashSet<&str> {
+  let mut stack: Vec<&str> = config
+    .graphql
+    .types
+    .values()
+    .flat_map(|type_| type_.fields.values())
+    .flat_map(|field| field.args.values())
+    .map(|arg| arg.type_of.as_str())
+    .collect();
+  let mut used = HashSet::new();
+  while let Some(name) = stack.pop() {
+    if used.insert(name) {
+      if let Some(type_) = config.find_type(name) {
+        stack.extend(type_.fields.values().map(|field| field.type_of.as_str()));
+      }
+    }
+  }
+  used
+}
+
+pub fn validate_no_orphan_input_types(config: &Config) -> Valid<(), String> {
+  let used = used_input_types(config);
+  let mut orphans: Vec<&String> = config
+    .input_types()
+    .into_iter()
+    .filter(|name| !used.contains(name.as_str()))
+    .collect();
+  orphans.sort();
+  orphans
+    .into_iter()
+    .validate_all(|name| Valid::<(), String>::fail("input type is not used by any argument".to_string()).trace(name))?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+  use crate::valid::{Valid, ValidExtensions};
+
+  #[test]
+  fn test_orphan_input_type() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+        mutation: Mutation
+      }
+
+      input PostInput {
+        title: String
+        author: AuthorInput
+      }
+
+      input AuthorInput {
+        name: String
+      }
+
+      input LegacyPostInput {
+        title: String
+      }
+
+      type Post {
+        id: Int
+        title: String
+      }
+
+      type Query {
+        posts: [Post] @http(path: "/posts")
+      }
+
+      type Mutation {
+        createPost(input: PostInput!): Post @http(path: "/posts", method: "POST", body: "{{args.input}}")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected =
+      Valid::<(), String>::fail("input type is not used by any argument".to_string()).trace("LegacyPostInput");
+    assert_eq!(config.validate_no_orphan_input_types(), expected);
+  }
+}
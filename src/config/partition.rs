@@ -0,0 +1,83 @@
+use crate::config::{Config, Type};
+
+fn is_object(type_: &Type) -> bool {
+  type_.variants.is_none() && !type_.scalar
+}
+
+pub fn input_object_types(config: &Config) -> Vec<(&String, &Type)> {
+  let input_types = config.input_types();
+  config
+    .graphql
+    .types
+    .iter()
+    .filter(|(name, type_)| is_object(type_) && input_types.contains(name))
+    .collect()
+}
+
+pub fn object_types(config: &Config) -> Vec<(&String, &Type)> {
+  let input_types = config.input_types();
+  config
+    .graphql
+    .types
+    .iter()
+    .filter(|(name, type_)| is_object(type_) && !input_types.contains(name))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_partition_object_and_input_types() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+        mutation: Mutation
+      }
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      input AddressInput {
+        city: String
+      }
+
+      input UserInput {
+        name: String
+        role: Role
+        address: AddressInput
+      }
+
+      input UnusedInput {
+        id: Int
+      }
+
+      type Query {
+        users: [User] @http(path: "/users")
+      }
+
+      type Mutation {
+        createUser(input: UserInput): User @http(path: "/users", method: POST, body: "{{args.input}}")
+      }
+
+      type User {
+        id: Int
+        name: String
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+
+    let names =
+      |types: Vec<(&String, &crate::config::Type)>| types.into_iter().map(|(name, _)| name.clone()).collect::<Vec<_>>();
+    assert_eq!(
+      names(config.input_object_types()),
+      vec!["AddressInput", "UnusedInput", "UserInput"]
+    );
+    assert_eq!(names(config.object_types()), vec!["Mutation", "Query", "User"]);
+  }
+}
@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use serde_json::{json, Value};
+
+use crate::config::{Arg, Config, Field, Http, Operation};
+use crate::json::JsonLike;
+use crate::mustache::Mustache;
+use crate::path_string::PathString;
+
+const SCHEMA: &str = "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+// Fills `{{args.*}}` from argument defaults, anything else is kept as a Postman variable with the same name.
+struct Example<'a> {
+  args: &'a BTreeMap<String, Arg>,
+}
+
+impl PathString for Example<'_> {
+  fn path_string<T: AsRef<str>>(&self, path: &[T]) -> Option<Cow<'_, str>> {
+    let parts: Vec<&str> = path.iter().map(AsRef::as_ref).collect();
+    let default = match parts.as_slice() {
+      ["args", name, rest @ ..] => self
+        .args
+        .get(*name)
+        .and_then(|arg| arg.default_value.as_ref())
+        .and_then(|value| value.get_path(rest)),
+      _ => None,
+    };
+    match default {
+      Some(Value::String(value)) => Some(Cow::Borrowed(value.as_str())),
+      Some(value) => Some(Cow::Owned(value.to_string())),
+      None => Some(Cow::Owned(format!("{{{{{}}}}}", parts.join(".")))),
+    }
+  }
+}
+
+fn render(template: &str, example: &Example) -> String {
+  match Mustache::parse(template) {
+    Ok(mustache) => mustache.render(example),
+    Err(_) => template.to_string(),
+  }
+}
+
+fn request(config: &Config, field: &Field, http: &Http) -> Value {
+  let example = Example { args: &field.args };
+  let base_url = http.base_url.as_ref().or(config.upstream.base_url.as_ref());
+  let mut url = format!(
+    "{}{}",
+    base_url.map_or("", |base_url| base_url.trim_end_matches('/')),
+    render(&http.path, &example)
+  );
+  let query: Vec<String> = http
+    .query
+    .iter()
+    .map(|param| format!("{}={}", param.key, render(&param.value, &example)))
+    .collect();
+  if !query.is_empty() {
+    url = format!("{}?{}", url, query.join("&"));
+  }
+  let headers: Vec<Value> = http
+    .headers
+    .iter()
+    .map(|(key, value)| json!({"key": key, "value": render(value, &example)}))
+    .collect();
+
+  let mut request = json!({"method": http.method, "header": headers, "url": {"raw": url}});
+  if let Some(body) = http.body.as_ref() {
+    request["body"] = json!({"mode": "raw", "raw": render(body, &example), "options": {"raw": {"language": "json"}}});
+  }
+  request
+}
+
+pub fn to_postman(config: &Config) -> Value {
+  let items: Vec<Value> = config
+    .entry_points()
+    .into_iter()
+    .filter_map(|(operation, name)| {
+      let root = match operation {
+        Operation::Query => config.graphql.schema.query.as_ref(),
+        Operation::Mutation => config.graphql.schema.mutation.as_ref(),
+        Operation::Subscription => config.graphql.schema.subscription.as_ref(),
+      }?;
+      let field = config.find_type(root)?.fields.get(&name)?;
+      let http = field.http.as_ref()?;
+      Some(json!({"name": format!("{}.{}", root, name), "request": request(config, field, http)}))
+    })
+    .collect();
+
+  json!({"info": {"name": "Tailcall", "schema": SCHEMA}, "item": items})
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_to_postman() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com/") {
+        query: Query
+        mutation: Mutation
+      }
+
+      input PostInput {
+        title: String
+      }
+
+      type Post {
+        id: Int
+        title: String
+      }
+
+      type Query {
+        post(id: Int = 1): Post @http(path: "/posts/{{args.id}}")
+        posts(userId: Int): [Post] @http(path: "/posts", query: [{key: "userId", value: "{{args.userId}}"}])
+        greeting: String @const(data: "hello")
+      }
+
+      type Mutation {
+        createPost(input: PostInput = {title: "Hello"}): Post
+          @http(path: "/posts", method: "POST", body: "{{args.input}}", headers: [{key: "x-api-key", value: "{{env.API_KEY}}"}])
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let collection = config.to_postman();
+
+    let names: Vec<_> = collection["item"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|item| item["name"].as_str().unwrap())
+      .collect();
+    assert_eq!(names, vec!["Query.post", "Query.posts", "Mutation.createPost"]);
+
+    assert_eq!(
+      collection["item"][0]["request"],
+      json!({"method": "GET", "header": [], "url": {"raw": "http://jsonplaceholder.typicode.com/posts/1"}})
+    );
+    assert_eq!(
+      collection["item"][1]["request"]["url"]["raw"],
+      "http://jsonplaceholder.typicode.com/posts?userId={{args.userId}}"
+    );
+    assert_eq!(
+      collection["item"][2]["request"],
+      json!({
+        "method": "POST",
+        "header": [{"key": "x-api-key", "value": "{{env.API_KEY}}"}],
+        "url": {"raw": "http://jsonplaceholder.typicode.com/posts"},
+        "body": {"mode": "raw", "raw": "{\"title\":\"Hello\"}", "options": {"raw": {"language": "json"}}}
+      })
+    );
+  }
+}
@@ -0,0 +1,194 @@
+use crate::config::{Config, Field};
+
+fn scalar_type(config: &Config, type_of: &str) -> String {
+  match type_of {
+    "Int" => "int32".to_string(),
+    "Float" => "double".to_string(),
+    "String" | "ID" | "JSON" => "string".to_string(),
+    "Boolean" => "bool".to_string(),
+    name if config.find_type(name).is_some_and(|type_| type_.scalar) => "string".to_string(),
+    name => name.to_string(),
+  }
+}
+
+fn field_type(config: &Config, field: &Field) -> String {
+  let type_of = scalar_type(config, &field.type_of);
+  if field.list {
+    format!("repeated {}", type_of)
+  } else {
+    type_of
+  }
+}
+
+// proto3 enum values share the scope of the enclosing package, so they are prefixed with the enum name
+// (eg: `OrderStatus.PAID` becomes `ORDER_STATUS_PAID`).
+fn enum_value_prefix(name: &str) -> String {
+  let mut prefix = String::new();
+  for (i, c) in name.chars().enumerate() {
+    if c.is_uppercase() && i > 0 {
+      prefix.push('_');
+    }
+    prefix.extend(c.to_uppercase());
+  }
+  prefix
+}
+
+pub fn to_proto(config: &Config) -> String {
+  let mut declarations = vec!["syntax = \"proto3\";".to_string()];
+
+  for (name, type_) in config.graphql.types.iter() {
+    if type_.scalar {
+      continue;
+    } else if let Some(variants) = type_.variants.as_ref() {
+      let prefix = enum_value_prefix(name);
+      // proto3 requires the first value to be zero, it stands for an unset enum
+      let mut lines = vec![format!("enum {} {{", name), format!("  {}_UNSPECIFIED = 0;", prefix)];
+      lines.extend(
+        variants
+          .iter()
+          .enumerate()
+          .map(|(i, variant)| format!("  {}_{} = {};", prefix, variant, i + 1)),
+      );
+      lines.push("}".to_string());
+      declarations.push(lines.join("\n"));
+    } else {
+      let mut lines = vec![format!("message {} {{", name)];
+      lines.extend(
+        type_
+          .fields
+          .iter()
+          .enumerate()
+          .map(|(i, (name, field))| format!("  {} {} = {};", field_type(config, field), name, i + 1)),
+      );
+      lines.push("}".to_string());
+      declarations.push(lines.join("\n"));
+    }
+  }
+
+  for (name, union) in config.graphql.unions.iter() {
+    let mut lines = vec![format!("message {} {{", name), "  oneof value {".to_string()];
+    lines.extend(
+      union
+        .types
+        .iter()
+        .enumerate()
+        .map(|(i, type_)| format!("    {} {} = {};", type_, type_.to_lowercase(), i + 1)),
+    );
+    lines.push("  }".to_string());
+    lines.push("}".to_string());
+    declarations.push(lines.join("\n"));
+  }
+
+  declarations.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use stripmargin::StripMargin;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_to_proto() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      enum AccountKind {
+        GUEST
+        USER
+      }
+
+      scalar Date
+
+      union Entity = User | Post
+
+      input PostInput {
+        title: String!
+      }
+
+      type User {
+        id: Int!
+        name: String
+        active: Boolean
+        role: Role
+        kind: AccountKind
+        joined: Date
+      }
+
+      type Post {
+        id: ID!
+        score: Float
+        tags: [String]
+      }
+
+      type Query {
+        user(id: Int!): User @http(path: "/users/{{args.id}}")
+        posts: [Post] @http(path: "/posts")
+      }
+
+      type Mutation {
+        createPost(input: PostInput!): Post @http(path: "/posts", method: "POST", body: "{{args.input}}")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = r#"|syntax = "proto3";
+                      |
+                      |enum AccountKind {
+                      |  ACCOUNT_KIND_UNSPECIFIED = 0;
+                      |  ACCOUNT_KIND_GUEST = 1;
+                      |  ACCOUNT_KIND_USER = 2;
+                      |}
+                      |
+                      |message Mutation {
+                      |  Post createPost = 1;
+                      |}
+                      |
+                      |message Post {
+                      |  string id = 1;
+                      |  double score = 2;
+                      |  repeated string tags = 3;
+                      |}
+                      |
+                      |message PostInput {
+                      |  string title = 1;
+                      |}
+                      |
+                      |message Query {
+                      |  repeated Post posts = 1;
+                      |  User user = 2;
+                      |}
+                      |
+                      |enum Role {
+                      |  ROLE_UNSPECIFIED = 0;
+                      |  ROLE_ADMIN = 1;
+                      |  ROLE_USER = 2;
+                      |}
+                      |
+                      |message User {
+                      |  bool active = 1;
+                      |  int32 id = 2;
+                      |  string joined = 3;
+                      |  AccountKind kind = 4;
+                      |  string name = 5;
+                      |  Role role = 6;
+                      |}
+                      |
+                      |message Entity {
+                      |  oneof value {
+                      |    Post post = 1;
+                      |    User user = 2;
+                      |  }
+                      |}"#
+      .strip_margin();
+
+    assert_eq!(config.to_proto(), expected);
+  }
+}
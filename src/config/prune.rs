@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use crate::config::Config;
+
+pub(super) fn dependencies<'a>(config: &'a Config, name: &str) -> Vec<&'a String> {
+  let mut dependencies = Vec::new();
+  if let Some(type_) = config.find_type(name) {
+    for field in type_.fields.values() {
+      dependencies.push(&field.type_of);
+      dependencies.extend(field.args.values().map(|arg| &arg.type_of));
+    }
+    dependencies.extend(type_.implements.iter());
+  }
+  if let Some(union_) = config.find_union(name) {
+    dependencies.extend(union_.types.iter());
+  }
+  dependencies
+}
+
+pub fn prune(config: &mut Config, roots: &[&str]) {
+  let schema = &config.graphql.schema;
+  let root_types: Vec<String> = [&schema.query, &schema.mutation, &schema.subscription]
+    .into_iter()
+    .flatten()
+    .cloned()
+    .collect();
+
+  for root_type in root_types.iter() {
+    if let Some(type_) = config.graphql.types.get_mut(root_type) {
+      type_.fields.retain(|name, _| roots.contains(&name.as_str()));
+    }
+  }
+
+  let mut stack = root_types.clone();
+  let mut reachable = HashSet::new();
+  while let Some(name) = stack.pop() {
+    if reachable.insert(name.clone()) {
+      stack.extend(dependencies(config, &name).into_iter().cloned());
+    }
+  }
+
+  config.graphql.types.retain(|name, _| reachable.contains(name));
+  config.graphql.unions.retain(|name, _| reachable.contains(name));
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_prune_to_single_field() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+        mutation: Mutation
+      }
+
+      union Entity = User | Comment
+
+      type Query {
+        user(role: Role): User @http(path: "/users/1")
+        posts: [Post] @http(path: "/posts")
+        search: [Entity] @http(path: "/search")
+      }
+
+      type Mutation {
+        createPost(title: String): Post @http(path: "/posts", method: POST)
+      }
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      type User {
+        id: Int
+        address: Address
+      }
+
+      type Address {
+        city: String
+      }
+
+      type Post {
+        id: Int
+        title: String
+      }
+
+      type Comment {
+        body: String
+      }
+    "#;
+    let mut config = Config::from_sdl(sdl).unwrap();
+    config.prune(&["user"]);
+
+    let types: Vec<_> = config.graphql.types.keys().cloned().collect();
+    assert_eq!(types, vec!["Address", "Mutation", "Query", "Role", "User"]);
+    let query_fields: Vec<_> = config.graphql.types["Query"].fields.keys().cloned().collect();
+    assert_eq!(query_fields, vec!["user"]);
+    assert!(config.graphql.types["Mutation"].fields.is_empty());
+    assert!(config.graphql.unions.is_empty());
+  }
+}
@@ -0,0 +1,54 @@
+use crate::config::Config;
+use crate::valid::{Valid, ValidExtensions, VectorExtension};
+
+pub fn lint_required_defaults(config: &Config) -> Valid<(), String> {
+  config.graphql.types.iter().validate_all(|(type_name, type_)| {
+    type_
+      .fields
+      .iter()
+      .validate_all(|(field_name, field)| {
+        field
+          .args
+          .iter()
+          .filter(|(_, arg)| arg.required && arg.default_value.is_some())
+          .validate_all(|(arg_name, _)| {
+            Valid::<(), String>::fail("argument is required, so its default value is never used".to_string())
+              .trace(arg_name)
+          })
+          .trace(field_name)
+      })
+      .trace(type_name)
+  })?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::config::Config;
+  use crate::valid::{Valid, ValidExtensions};
+
+  #[test]
+  fn test_required_argument_with_default() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        posts(limit: Int! = 5, offset: Int = 0): [Post] @http(path: "/posts")
+        post(id: Int!): Post @http(path: "/posts/{{args.id}}")
+      }
+
+      type Post {
+        id: Int
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let actual = config.lint_required_defaults();
+    let expected = Valid::fail("argument is required, so its default value is never used".to_string())
+      .trace("limit")
+      .trace("posts")
+      .trace("Query");
+    assert_eq!(actual, expected);
+  }
+}
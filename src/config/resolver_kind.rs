@@ -0,0 +1,74 @@
+use crate::config::Field;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResolverKind {
+  Http,
+  Unsafe,
+  Const,
+  Sse,
+  Expr,
+  None,
+}
+
+impl Field {
+  /// Returns the highest-precedence resolver of the field. Fields with more
+  /// than one resolver are rejected separately while building the blueprint.
+  pub fn resolver_kind(&self) -> ResolverKind {
+    if self.http.is_some() {
+      ResolverKind::Http
+    } else if self.unsafe_operation.is_some() {
+      ResolverKind::Unsafe
+    } else if self.const_field.is_some() {
+      ResolverKind::Const
+    } else if self.sse.is_some() {
+      ResolverKind::Sse
+    } else if self.expr.is_some() {
+      ResolverKind::Expr
+    } else {
+      ResolverKind::None
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::ResolverKind;
+  use crate::config::{ConstField, Expr, Field, Http, Sse, Unsafe};
+
+  fn http() -> Http {
+    Http { path: "/users".to_string(), ..Default::default() }
+  }
+
+  #[test]
+  fn test_resolver_kind() {
+    let sse = Sse { url: "http://localhost/events".to_string(), event: None };
+    let cases = vec![
+      (Field::default().http(http()), ResolverKind::Http),
+      (
+        Field::default().unsafe_operation(Unsafe { script: "ctx".to_string() }),
+        ResolverKind::Unsafe,
+      ),
+      (
+        Field::default().const_field(ConstField::new(json!(1))),
+        ResolverKind::Const,
+      ),
+      (Field::default().sse(sse), ResolverKind::Sse),
+      (Field::default().expr(Expr { body: json!("hello") }), ResolverKind::Expr),
+      (Field::default(), ResolverKind::None),
+    ];
+    for (field, expected) in cases {
+      assert_eq!(field.resolver_kind(), expected);
+    }
+  }
+
+  #[test]
+  fn test_resolver_kind_precedence() {
+    let field = Field::default()
+      .expr(Expr { body: json!("hello") })
+      .const_field(ConstField::new(json!(1)))
+      .http(http());
+    assert_eq!(field.resolver_kind(), ResolverKind::Http);
+  }
+}
@@ -0,0 +1,79 @@
+use serde_json::Value;
+
+use crate::config::Config;
+
+fn without_docs(config: &Config) -> Config {
+  let mut config = config.clone();
+  for type_ in config.graphql.types.values_mut() {
+    type_.doc = None;
+    for field in type_.fields.values_mut() {
+      field.doc = None;
+      field.args.values_mut().for_each(|arg| arg.doc = None);
+    }
+  }
+  config.graphql.unions.values_mut().for_each(|union_| union_.doc = None);
+  config
+}
+
+// Fields, arguments and types are kept in sorted maps, so declaration order is already normalized away.
+fn normalized(config: &Config) -> Option<Value> {
+  serde_json::to_value(without_docs(config)).ok()
+}
+
+pub fn semantically_eq(config: &Config, other: &Config) -> bool {
+  match (normalized(config), normalized(other)) {
+    (Some(left), Some(right)) => left == right,
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::config::Config;
+
+  const SDL: &str = r#"
+    schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+      query: Query
+    }
+
+    type Query {
+      user(id: Int!): User @http(path: "/users/{{args.id}}")
+    }
+
+    type User {
+      id: Int
+      name: String
+    }
+  "#;
+
+  #[test]
+  fn test_configs_differing_in_docs_are_equal() {
+    let documented = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      "A user of the blog"
+      type User {
+        "User's name"
+        name: String
+        id: Int
+      }
+
+      type Query {
+        "Finds a user"
+        user("The user id" id: Int!): User @http(path: "/users/{{args.id}}")
+      }
+    "#;
+    let left = Config::from_sdl(SDL).unwrap();
+    let right = Config::from_sdl(documented).unwrap();
+    assert!(left.semantically_eq(&right));
+  }
+
+  #[test]
+  fn test_configs_differing_in_directives_are_not_equal() {
+    let left = Config::from_sdl(SDL).unwrap();
+    let right = Config::from_sdl(&SDL.replace("/users/{{args.id}}", "/people/{{args.id}}")).unwrap();
+    assert!(!left.semantically_eq(&right));
+  }
+}
@@ -95,6 +95,24 @@ impl Server {
   }
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, Default, Setters)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Dns {
+  pub servers: Vec<String>,
+  #[serde(skip_serializing_if = "is_default")]
+  pub ttl: Option<u64>,
+}
+
+/// Client certificate and key file paths for mTLS upstreams, loaded at runtime.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+#[serde(default)]
+pub struct Tls {
+  #[serde(skip_serializing_if = "is_default")]
+  pub cert: Option<String>,
+  #[serde(skip_serializing_if = "is_default")]
+  pub key: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
 pub struct Proxy {
   pub url: String,
@@ -132,6 +150,10 @@ pub struct Upstream {
   pub enable_http_cache: Option<bool>,
   #[serde(skip_serializing_if = "is_default")]
   pub batch: Option<Batch>,
+  #[serde(skip_serializing_if = "is_default")]
+  pub dns: Option<Dns>,
+  #[serde(skip_serializing_if = "is_default")]
+  pub tls: Option<Tls>,
 }
 
 impl Upstream {
@@ -190,6 +212,8 @@ impl Upstream {
     self.tcp_keep_alive = other.tcp_keep_alive.or(self.tcp_keep_alive);
     self.timeout = other.timeout.or(self.timeout);
     self.user_agent = other.user_agent.or(self.user_agent);
+    self.dns = other.dns.or(self.dns);
+    self.tls = other.tls.or(self.tls);
     self.batch = other.batch.map(|other| {
       let mut batch = self.batch.unwrap_or_default();
       batch.max_size = other.max_size;
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use super::prune::dependencies;
+use crate::config::{Config, Field, Type};
+
+fn field_tagged(field: &Field, type_: Option<&Type>, tag: &str) -> bool {
+  field.tags.iter().any(|name| name == tag) || type_.is_some_and(|type_| type_.tags.iter().any(|name| name == tag))
+}
+
+pub fn split_by_tag(config: &Config, tag: &str) -> Config {
+  let mut slice = config.clone();
+  let schema = &config.graphql.schema;
+  let root_types: Vec<String> = [&schema.query, &schema.mutation, &schema.subscription]
+    .into_iter()
+    .flatten()
+    .cloned()
+    .collect();
+
+  // Root types keep the fields that are tagged or return a tagged type, other types keep only their tagged fields
+  // unless the whole type is tagged or none of its fields are.
+  let mut seeds = root_types.clone();
+  for (name, type_) in slice.graphql.types.iter_mut() {
+    let is_root = root_types.contains(name);
+    let type_tagged = type_.tags.iter().any(|name| name == tag);
+    let has_tagged_fields = type_
+      .fields
+      .values()
+      .any(|field| field.tags.iter().any(|name| name == tag));
+    if is_root {
+      type_
+        .fields
+        .retain(|_, field| field_tagged(field, config.find_type(&field.type_of), tag));
+    } else if type_tagged {
+      seeds.push(name.clone());
+    } else if has_tagged_fields {
+      type_
+        .fields
+        .retain(|_, field| field.tags.iter().any(|name| name == tag));
+      seeds.push(name.clone());
+    }
+  }
+
+  let mut reachable = HashSet::new();
+  while let Some(name) = seeds.pop() {
+    if reachable.insert(name.clone()) {
+      seeds.extend(dependencies(&slice, &name).into_iter().cloned());
+    }
+  }
+
+  slice.graphql.types.retain(|name, _| reachable.contains(name));
+  slice.graphql.unions.retain(|name, _| reachable.contains(name));
+  slice
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_split_pii_slice() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      type Query {
+        users: [User] @http(path: "/users") @tag(name: "pii")
+        posts: [Post] @http(path: "/posts")
+        payment: Payment @http(path: "/payment")
+      }
+
+      type User {
+        id: Int
+        email: String @tag(name: "pii")
+        address: Address @tag(name: "pii")
+        posts: [Post]
+      }
+
+      type Address {
+        city: String
+      }
+
+      type Payment @tag(name: "pii") {
+        card: String
+      }
+
+      type Post {
+        id: Int
+        title: String
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let slice = config.split_by_tag("pii");
+
+    let types: Vec<_> = slice.graphql.types.keys().cloned().collect();
+    assert_eq!(types, vec!["Address", "Payment", "Query", "User"]);
+    let fields = |name: &str| slice.graphql.types[name].fields.keys().cloned().collect::<Vec<_>>();
+    assert_eq!(fields("Query"), vec!["payment", "users"]);
+    assert_eq!(fields("User"), vec!["address", "email"]);
+    assert_eq!(fields("Address"), vec!["city"]);
+    assert_eq!(config.graphql.types["User"].fields.len(), 4);
+  }
+}
@@ -0,0 +1,135 @@
+use crate::config::{Config, Field};
+
+// Only fields holding a single stored value become columns, lists and nested objects live in their own tables.
+fn column_type(config: &Config, field: &Field) -> Option<&'static str> {
+  if field.list || field.has_resolver() {
+    return None;
+  }
+  match field.type_of.as_str() {
+    "Int" => Some("INTEGER"),
+    "Float" => Some("DOUBLE PRECISION"),
+    "String" | "ID" => Some("TEXT"),
+    "Boolean" => Some("BOOLEAN"),
+    "JSON" => Some("JSON"),
+    name => match config.find_type(name) {
+      Some(type_) if type_.scalar || type_.variants.is_some() => Some("TEXT"),
+      _ => None,
+    },
+  }
+}
+
+// Identifiers are always quoted, so that names like `order` or `user` aren't read as keywords.
+fn quote(identifier: &str) -> String {
+  format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+pub fn to_sql_ddl(config: &Config) -> String {
+  let mut statements = Vec::new();
+
+  for (type_name, type_) in config.graphql.types.iter() {
+    let Some(model) = type_.model.as_ref() else {
+      continue;
+    };
+    let columns: Vec<String> = type_
+      .fields
+      .iter()
+      .filter_map(|(name, field)| {
+        column_type(config, field).map(|column_type| {
+          if field.required {
+            format!("  {} {} NOT NULL", quote(name), column_type)
+          } else {
+            format!("  {} {}", quote(name), column_type)
+          }
+        })
+      })
+      .collect();
+    // A table needs at least one column, so types without any are reported instead of emitting invalid SQL.
+    if columns.is_empty() {
+      statements.push(format!(
+        "-- {} is skipped, type '{}' has no fields that can be stored as columns",
+        quote(&model.table),
+        type_name
+      ));
+      continue;
+    }
+    statements.push(format!(
+      "CREATE TABLE {} (\n{}\n);",
+      quote(&model.table),
+      columns.join(",\n")
+    ));
+  }
+
+  statements.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use stripmargin::StripMargin;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_to_sql_ddl() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      scalar Date
+
+      type User @model(table: "users") {
+        id: Int!
+        name: String!
+        email: String
+        active: Boolean
+        score: Float
+        role: Role!
+        joined: Date
+        tags: [String]
+        posts: [Post] @http(path: "/users/{{value.id}}/posts")
+      }
+
+      type Post @model(table: "posts") {
+        id: ID!
+        body: JSON
+        order: Int
+        user: User
+      }
+
+      type Tag @model(table: "tags") {
+        posts: [Post]
+      }
+
+      type Query {
+        users: [User] @http(path: "/users")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = r#"|CREATE TABLE "posts" (
+                      |  "body" JSON,
+                      |  "id" TEXT NOT NULL,
+                      |  "order" INTEGER
+                      |);
+                      |
+                      |-- "tags" is skipped, type 'Tag' has no fields that can be stored as columns
+                      |
+                      |CREATE TABLE "users" (
+                      |  "active" BOOLEAN,
+                      |  "email" TEXT,
+                      |  "id" INTEGER NOT NULL,
+                      |  "joined" TEXT,
+                      |  "name" TEXT NOT NULL,
+                      |  "role" TEXT NOT NULL,
+                      |  "score" DOUBLE PRECISION
+                      |);"#
+      .strip_margin();
+
+    assert_eq!(config.to_sql_ddl(), expected);
+  }
+}
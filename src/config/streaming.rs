@@ -0,0 +1,330 @@
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Read};
+
+use async_graphql::parser::types::{TypeKind, TypeSystemDefinition};
+
+use super::from_document::{
+  merge_extensions, pos_name_to_string, server, to_root_schema, to_type, to_union, upstream, validate_reserved_names,
+  validate_root_types,
+};
+use crate::config::{Config, GraphQL, RootSchema, Server, Type, Union, Upstream};
+use crate::valid::{Valid, ValidExtensions, ValidationError};
+
+const DEFINITION_KEYWORDS: [&str; 9] = [
+  "schema",
+  "type",
+  "interface",
+  "union",
+  "enum",
+  "input",
+  "scalar",
+  "directive",
+  "extend",
+];
+
+/// Builds a [Config] from SDL read incrementally, one top-level definition at a time.
+/// Each definition is parsed and converted on its own so the full document AST is never held in memory.
+/// The result is the same as parsing the whole document and converting it with `Config::try_from`.
+pub fn from_document_streaming(reader: impl Read) -> Valid<Config, String> {
+  let mut splitter = DefinitionSplitter::default();
+  let mut builder = ConfigBuilder::default();
+  for line in BufReader::new(reader).lines() {
+    let line = line.map_err(|e| ValidationError::new(e.to_string()))?;
+    for chunk in splitter.push_line(&line) {
+      builder.push_chunk(&chunk)?;
+    }
+  }
+  if let Some(chunk) = splitter.finish() {
+    builder.push_chunk(&chunk)?;
+  }
+  builder.finish()
+}
+
+// Splits SDL into chunks that each start at a top-level definition (including its description).
+#[derive(Default)]
+struct DefinitionSplitter {
+  pending: String,
+  // count of bytes already handed out as chunks, so positions below are absolute within the stream
+  drained: usize,
+  depth: i32,
+  in_block_string: bool,
+  description_start: Option<usize>,
+  after_extend: bool,
+  has_definition: bool,
+}
+
+impl DefinitionSplitter {
+  fn push_line(&mut self, line: &str) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let offset = self.drained + self.pending.len();
+    self.pending.push_str(line);
+    self.pending.push('\n');
+
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+      if self.in_block_string {
+        match line[i..].find("\"\"\"") {
+          Some(end) if end > 0 && bytes[i + end - 1] == b'\\' => i += end + 3,
+          Some(end) => {
+            self.in_block_string = false;
+            i += end + 3;
+          }
+          None => break,
+        }
+        continue;
+      }
+      match bytes[i] {
+        b'#' => break,
+        b'"' => {
+          if self.depth == 0 && self.description_start.is_none() {
+            self.description_start = Some(offset + i);
+          }
+          if line[i..].starts_with("\"\"\"") {
+            self.in_block_string = true;
+            i += 3;
+          } else {
+            i += 1;
+            while i < bytes.len() && bytes[i] != b'"' {
+              i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i += 1;
+          }
+        }
+        b'{' | b'(' | b'[' => {
+          self.depth += 1;
+          i += 1;
+        }
+        b'}' | b')' | b']' => {
+          self.depth -= 1;
+          i += 1;
+        }
+        c if c.is_ascii_alphabetic() || c == b'_' => {
+          let start = i;
+          while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+            i += 1;
+          }
+          let word = &line[start..i];
+          if self.depth == 0 && DEFINITION_KEYWORDS.contains(&word) {
+            if self.after_extend {
+              self.after_extend = false;
+            } else {
+              let boundary = self.description_start.unwrap_or(offset + start);
+              if self.has_definition && boundary > self.drained {
+                chunks.push(self.pending.drain(..boundary - self.drained).collect());
+                self.drained = boundary;
+              }
+              self.has_definition = true;
+              self.after_extend = word == "extend";
+            }
+            self.description_start = None;
+          }
+        }
+        _ => i += 1,
+      }
+    }
+    chunks
+  }
+
+  fn finish(self) -> Option<String> {
+    if self.pending.trim().is_empty() {
+      None
+    } else {
+      Some(self.pending)
+    }
+  }
+}
+
+type SchemaParts = (Valid<Server, String>, Valid<Upstream, String>, RootSchema);
+
+// Accumulates converted definitions, keeping errors in the order `Config::try_from` would report them.
+#[derive(Default)]
+struct ConfigBuilder {
+  schema: Option<SchemaParts>,
+  reserved_names: ValidationError<String>,
+  object_kinds: BTreeMap<String, bool>,
+  types: BTreeMap<String, Type>,
+  type_error: Option<ValidationError<String>>,
+  extensions: Vec<(String, Valid<Option<Type>, String>)>,
+  unions: BTreeMap<String, Union>,
+}
+
+impl ConfigBuilder {
+  fn push_chunk(&mut self, chunk: &str) -> Valid<(), String> {
+    let doc = async_graphql::parser::parse_schema(chunk).map_err(|e| ValidationError::new(e.to_string()))?;
+    for definition in doc.definitions {
+      match definition {
+        TypeSystemDefinition::Schema(schema_definition) => {
+          if self.schema.is_none() {
+            let schema_definition = schema_definition.node;
+            self.schema = Some((
+              server(&schema_definition),
+              upstream(&schema_definition),
+              to_root_schema(&schema_definition),
+            ));
+          }
+        }
+        TypeSystemDefinition::Type(type_definition) => {
+          let type_definition = type_definition.node;
+          let type_name = pos_name_to_string(&type_definition.name);
+          if let Err(e) = validate_reserved_names(&type_definition) {
+            self.reserved_names = std::mem::replace(&mut self.reserved_names, ValidationError::empty()).combine(e);
+          }
+          self
+            .object_kinds
+            .entry(type_name.clone())
+            .or_insert(matches!(type_definition.kind, TypeKind::Object(_)));
+          if type_definition.extend {
+            self.extensions.push((type_name.clone(), to_type(&type_definition)));
+          } else if self.type_error.is_none() {
+            match to_type(&type_definition) {
+              Ok(Some(type_)) => {
+                self.types.insert(type_name.clone(), type_);
+              }
+              Ok(None) => {}
+              Err(e) => self.type_error = Some(e),
+            }
+          }
+          if let TypeKind::Union(union_type) = type_definition.kind {
+            let doc = type_definition.description.map(|pos| pos.node);
+            self.unions.insert(type_name, to_union(union_type, &doc));
+          }
+        }
+        TypeSystemDefinition::Directive(_) => {}
+      }
+    }
+    Valid::Ok(())
+  }
+
+  fn finish(self) -> Valid<Config, String> {
+    let (server, upstream, root_schema) = self
+      .schema
+      .ok_or(ValidationError::new("schema not found".to_string()))
+      .trace("schema")?;
+    let server = server?;
+    let upstream = upstream?;
+    if !self.reserved_names.is_empty() {
+      return Err(self.reserved_names);
+    }
+    validate_root_types(&root_schema, &self.object_kinds)?;
+    if let Some(e) = self.type_error {
+      return Err(e);
+    }
+    let types = merge_extensions(self.types, self.extensions)?;
+
+    Valid::Ok(Config { server, upstream, graphql: GraphQL { schema: root_schema, types, unions: self.unions } })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::{from_document_streaming, DefinitionSplitter};
+  use crate::config::Config;
+
+  #[test]
+  fn test_streaming_matches_from_sdl() {
+    let sdl = r#"
+      schema
+        @server(port: 8000)
+        @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+        mutation: Mutation
+      }
+
+      """
+      A user of the blog
+      with a { brace } in the description
+      """
+      type User @tag(name: "people") {
+        id: Int!
+        "The user's name, e.g. \"type\""
+        name: String
+        role: Role
+      }
+
+      # a comment mentioning type Unknown
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      union Entity = User
+        | Post
+
+      type Post {
+        id: Int!
+        title: String
+        user: User @http(path: "/users/{{value.userId}}", query: [{key: "type", value: "full"}])
+      }
+
+      extend type Post {
+        body: String
+      }
+
+      input PostInput {
+        title: String
+      }
+
+      scalar Date
+
+      type Query {
+        posts: [Post] @http(path: "/posts")
+        entity: Entity @http(path: "/entity")
+      }
+
+      type Mutation {
+        createPost(input: PostInput!): Post @http(path: "/posts", method: "POST", body: "{{args.input}}")
+      }
+    "#;
+
+    let expected = Config::from_sdl(sdl).unwrap();
+    let actual = from_document_streaming(sdl.as_bytes()).unwrap();
+
+    assert_eq!(
+      serde_json::to_value(actual).unwrap(),
+      serde_json::to_value(expected).unwrap()
+    );
+  }
+
+  #[test]
+  fn test_streaming_errors_match_from_sdl() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      extend type Missing {
+        id: Int
+      }
+
+      type Query {
+        __users: [Int]
+      }
+    "#;
+
+    let expected = Config::from_sdl(sdl).unwrap_err();
+    let actual = from_document_streaming(sdl.as_bytes()).unwrap_err();
+
+    assert_eq!(actual, expected);
+  }
+
+  #[test]
+  fn test_splits_one_chunk_per_definition() {
+    let sdl = "schema { query: Query }\n\"The root\"\ntype Query { a: Int }\nextend type Query {\n  b: Int\n}\nunion U = A\n  | B\n";
+    let mut splitter = DefinitionSplitter::default();
+    let mut chunks: Vec<String> = sdl.lines().flat_map(|line| splitter.push_line(line)).collect();
+    chunks.extend(splitter.finish());
+
+    assert_eq!(
+      chunks,
+      vec![
+        "schema { query: Query }\n",
+        "\"The root\"\ntype Query { a: Int }\n",
+        "extend type Query {\n  b: Int\n}\n",
+        "union U = A\n  | B\n",
+      ]
+    );
+  }
+}
@@ -0,0 +1,87 @@
+use crate::config::Config;
+
+pub fn summary(config: &Config) -> String {
+  let types = config.graphql.types.values();
+  let enums = types.clone().filter(|type_| type_.variants.is_some()).count();
+  let scalars = types.clone().filter(|type_| type_.scalar).count();
+  let objects = types
+    .clone()
+    .filter(|type_| type_.variants.is_none() && !type_.scalar)
+    .count();
+  let fields: usize = types.map(|type_| type_.fields.len()).sum();
+  let schema = &config.graphql.schema;
+  let or_none = |value: &Option<String>| value.clone().unwrap_or("-".to_string());
+
+  [
+    format!("Types: {}", objects),
+    format!("Fields: {}", fields),
+    format!("Enums: {}", enums),
+    format!("Scalars: {}", scalars),
+    format!("Unions: {}", config.graphql.unions.len()),
+    format!("Query: {}", or_none(&schema.query)),
+    format!("Mutation: {}", or_none(&schema.mutation)),
+    format!("Subscription: {}", or_none(&schema.subscription)),
+    format!("Base URL: {}", or_none(&config.upstream.base_url)),
+  ]
+  .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use stripmargin::StripMargin;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_summary() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+        mutation: Mutation
+      }
+
+      scalar Email
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      union Entity = User | Post
+
+      type User {
+        id: Int
+        email: Email
+        role: Role
+      }
+
+      type Post {
+        id: Int
+        title: String
+      }
+
+      type Query {
+        users: [User] @http(path: "/users")
+        posts: [Post] @http(path: "/posts")
+      }
+
+      type Mutation {
+        createPost: Post @http(path: "/posts", method: "POST")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = r#"|Types: 4
+                      |Fields: 8
+                      |Enums: 1
+                      |Scalars: 1
+                      |Unions: 1
+                      |Query: Query
+                      |Mutation: Mutation
+                      |Subscription: -
+                      |Base URL: http://jsonplaceholder.typicode.com"#
+      .strip_margin();
+
+    assert_eq!(config.summary(), expected);
+  }
+}
@@ -0,0 +1,166 @@
+use crate::config::{Config, Field};
+
+// Custom scalars refer to the `unknown` alias emitted for them, `JSON` has no alias and would otherwise name
+// TypeScript's global `JSON` object.
+fn scalar_type(type_of: &str) -> String {
+  match type_of {
+    "Int" | "Float" => "number".to_string(),
+    "String" | "ID" => "string".to_string(),
+    "Boolean" => "boolean".to_string(),
+    "JSON" => "unknown".to_string(),
+    name => name.to_string(),
+  }
+}
+
+fn nullable(type_of: String, required: bool) -> String {
+  if required {
+    type_of
+  } else {
+    format!("{} | null", type_of)
+  }
+}
+
+fn field_type(field: &Field) -> String {
+  let type_of = scalar_type(&field.type_of);
+  if field.list {
+    format!("Array<{}>", nullable(type_of, field.list_type_required))
+  } else {
+    type_of
+  }
+}
+
+fn field_signature(name: &str, field: &Field) -> String {
+  let optional = if field.required { "" } else { "?" };
+  format!(
+    "  {}{}: {};",
+    name,
+    optional,
+    nullable(field_type(field), field.required)
+  )
+}
+
+pub fn to_typescript(config: &Config) -> String {
+  let mut declarations = Vec::new();
+
+  for (name, type_) in config.graphql.types.iter() {
+    if type_.scalar {
+      declarations.push(format!("export type {} = unknown;", name));
+    } else if let Some(variants) = type_.variants.as_ref() {
+      let mut lines = vec![format!("export enum {} {{", name)];
+      lines.extend(
+        variants
+          .iter()
+          .map(|variant| format!("  {} = \"{}\",", variant, variant)),
+      );
+      lines.push("}".to_string());
+      declarations.push(lines.join("\n"));
+    } else {
+      let mut lines = vec![format!("export interface {} {{", name)];
+      lines.extend(type_.fields.iter().map(|(name, field)| field_signature(name, field)));
+      lines.push("}".to_string());
+      declarations.push(lines.join("\n"));
+    }
+  }
+
+  for (name, union) in config.graphql.unions.iter() {
+    let types = union.types.iter().cloned().collect::<Vec<_>>();
+    declarations.push(format!("export type {} = {};", name, types.join(" | ")));
+  }
+
+  declarations.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use stripmargin::StripMargin;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_to_typescript() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+        mutation: Mutation
+      }
+
+      scalar Email
+
+      enum Role {
+        ADMIN
+        USER
+      }
+
+      union Entity = User | Post
+
+      input PostInput {
+        title: String!
+        tags: [String!]
+      }
+
+      type User {
+        id: Int!
+        email: Email
+        emails: [Email!]
+        metadata: JSON
+        role: Role
+        admin: Boolean
+      }
+
+      type Post {
+        id: ID!
+        score: Float
+        comments: [String]!
+      }
+
+      type Query {
+        posts: [Post!]! @http(path: "/posts")
+      }
+
+      type Mutation {
+        createPost(input: PostInput!): Post @http(path: "/posts", method: POST, body: "{{args.input}}")
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    let expected = r#"|export type Email = unknown;
+                      |
+                      |export interface Mutation {
+                      |  createPost?: Post | null;
+                      |}
+                      |
+                      |export interface Post {
+                      |  comments: Array<string | null>;
+                      |  id: string;
+                      |  score?: number | null;
+                      |}
+                      |
+                      |export interface PostInput {
+                      |  tags?: Array<string> | null;
+                      |  title: string;
+                      |}
+                      |
+                      |export interface Query {
+                      |  posts: Array<Post>;
+                      |}
+                      |
+                      |export enum Role {
+                      |  ADMIN = "ADMIN",
+                      |  USER = "USER",
+                      |}
+                      |
+                      |export interface User {
+                      |  admin?: boolean | null;
+                      |  email?: Email | null;
+                      |  emails?: Array<Email> | null;
+                      |  id: number;
+                      |  metadata?: unknown | null;
+                      |  role?: Role | null;
+                      |}
+                      |
+                      |export type Entity = Post | User;"#
+      .strip_margin();
+
+    assert_eq!(config.to_typescript(), expected);
+  }
+}
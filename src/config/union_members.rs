@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use crate::config::Config;
+
+pub fn unused_union_members(config: &Config) -> Vec<(String, String)> {
+  let returned: HashSet<&String> = config
+    .graphql
+    .types
+    .values()
+    .flat_map(|type_| type_.fields.values().map(|field| &field.type_of))
+    .collect();
+
+  config
+    .graphql
+    .unions
+    .iter()
+    .flat_map(|(union_name, union_)| {
+      union_
+        .types
+        .iter()
+        .filter(|member| !returned.contains(member))
+        .map(move |member| (union_name.clone(), member.clone()))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::config::Config;
+
+  #[test]
+  fn test_unused_union_members() {
+    let sdl = r#"
+      schema @server @upstream(baseURL: "http://jsonplaceholder.typicode.com") {
+        query: Query
+      }
+
+      union Entity = User | Comment
+
+      type Query {
+        search: [Entity] @http(path: "/search")
+        user: User @http(path: "/users/1")
+      }
+
+      type User {
+        id: Int
+      }
+
+      type Comment {
+        body: String
+      }
+    "#;
+    let config = Config::from_sdl(sdl).unwrap();
+    assert_eq!(
+      config.unused_union_members(),
+      vec![("Entity".to_string(), "Comment".to_string())]
+    );
+  }
+}
@@ -0,0 +1,89 @@
+use crate::config::Config;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Usage {
+  Field {
+    type_name: String,
+    field_name: String,
+  },
+  Argument {
+    type_name: String,
+    field_name: String,
+    arg_name: String,
+  },
+  UnionMember {
+    union_name: String,
+  },
+  Implementation {
+    type_name: String,
+  },
+}
+
+pub fn usages_of(config: &Config, name: &str) -> Vec<Usage> {
+  let mut usages = Vec::new();
+  for (type_name, type_) in config.graphql.types.iter() {
+    if type_.implements.contains(name) {
+      usages.push(Usage::Implementation { type_name: type_name.clone() });
+    }
+    for (field_name, field) in type_.fields.iter() {
+      if field.type_of == name {
+        usages.push(Usage::Field { type_name: type_name.clone(), field_name: field_name.clone() });
+      }
+      for (arg_name, arg) in field.args.iter() {
+        if arg.type_of == name {
+          usages.push(Usage::Argument {
+            type_name: type_name.clone(),
+            field_name: field_name.clone(),
+            arg_name: arg_name.clone(),
+          });
+        }
+      }
+    }
+  }
+  for (union_name, union_) in config.graphql.unions.iter() {
+    if union_.types.contains(name) {
+      usages.push(Usage::UnionMember { union_name: union_name.clone() });
+    }
+  }
+  usages
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use pretty_assertions::assert_eq;
+
+  use super::Usage;
+  use crate::config::{Arg, Config, Field, Type};
+
+  #[test]
+  fn test_usages_of_scalar() {
+    let email = || Field::default().type_of("Email".to_string());
+    let config = Config::default().query("Query").types(vec![
+      (
+        "Query",
+        Type::default().fields(vec![(
+          "user",
+          Field::default().type_of("User".to_string()).args(BTreeMap::from([(
+            "email".to_string(),
+            Arg { type_of: "Email".to_string(), ..Default::default() },
+          )])),
+        )]),
+      ),
+      (
+        "User",
+        Type::default().fields(vec![("email", email()), ("backupEmail", email())]),
+      ),
+      ("Email", Type { scalar: true, ..Default::default() }),
+    ]);
+
+    let actual = config.usages_of("Email");
+    let expected = vec![
+      Usage::Argument { type_name: "Query".to_string(), field_name: "user".to_string(), arg_name: "email".to_string() },
+      Usage::Field { type_name: "User".to_string(), field_name: "backupEmail".to_string() },
+      Usage::Field { type_name: "User".to_string(), field_name: "email".to_string() },
+    ];
+    assert_eq!(actual, expected);
+  }
+}
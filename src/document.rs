@@ -103,9 +103,10 @@ fn print_type_def(type_def: &TypeDefinition) -> String {
         String::new()
       };
       format!(
-        "interface {} {}{{\n{}\n}}\n",
+        "interface {} {}{}{{\n{}\n}}\n",
         type_def.name.node,
         implements,
+        print_type_directives(type_def),
         interface
           .fields
           .iter()
@@ -129,9 +130,10 @@ fn print_type_def(type_def: &TypeDefinition) -> String {
         String::new()
       };
       format!(
-        "type {} {}{{\n{}\n}}\n",
+        "type {} {}{}{{\n{}\n}}\n",
         type_def.name.node,
         implements,
+        print_type_directives(type_def),
         object
           .fields
           .iter()
@@ -152,6 +154,13 @@ fn print_type_def(type_def: &TypeDefinition) -> String {
     // Handle other type kinds...
   }
 }
+fn print_type_directives(type_def: &TypeDefinition) -> String {
+  type_def
+    .directives
+    .iter()
+    .map(|d| format!("{} ", print_directive(&const_directive_to_sdl(&d.node))))
+    .collect::<String>()
+}
 fn print_field(field: &async_graphql::parser::types::FieldDefinition) -> String {
   let directives: Vec<String> = field
     .directives
@@ -168,7 +177,20 @@ fn print_field(field: &async_graphql::parser::types::FieldDefinition) -> String
     let args = field
       .arguments
       .iter()
-      .map(|arg| format!("{}: {}", arg.node.name, arg.node.ty.node.base))
+      .map(|arg| {
+        let default_value = arg.node.default_value.as_ref();
+        let default_str = default_value.map_or(String::new(), |value| format!(" = {}", value.node));
+        let directives_str = arg
+          .node
+          .directives
+          .iter()
+          .map(|d| format!(" {}", print_directive(&const_directive_to_sdl(&d.node))))
+          .collect::<String>();
+        format!(
+          "{}: {}{}{}",
+          arg.node.name, arg.node.ty.node, default_str, directives_str
+        )
+      })
       .collect::<Vec<String>>()
       .join(", ");
     format!("({})", args)
@@ -1,9 +1,13 @@
 #![allow(clippy::too_many_arguments)]
 
+use std::collections::BTreeSet;
+
 use derive_setters::Setters;
 use hyper::HeaderMap;
 
+use crate::config::{Decode, QueryEncoding};
 use crate::http::Method;
+use crate::jq::JqFilter;
 use crate::json::JsonSchema;
 
 #[derive(Clone, Debug, Setters)]
@@ -16,6 +20,13 @@ pub struct Endpoint {
   pub headers: HeaderMap,
   pub body: Option<String>,
   pub description: Option<String>,
+  pub decode: Option<Decode>,
+  // Request headers forwarded upstream, every header in the context is forwarded when unset.
+  pub allowed_headers: Option<BTreeSet<String>>,
+  pub jq: Option<JqFilter>,
+  // Query params holding a list argument, they are sent as one param per item.
+  pub expand: BTreeSet<String>,
+  pub query_encoding: QueryEncoding,
 }
 
 impl Endpoint {
@@ -29,6 +40,11 @@ impl Endpoint {
       headers: Default::default(),
       body: Default::default(),
       description: Default::default(),
+      decode: Default::default(),
+      allowed_headers: Default::default(),
+      jq: Default::default(),
+      expand: Default::default(),
+      query_encoding: Default::default(),
     }
   }
 }
@@ -20,9 +20,7 @@ fn graphiql() -> Result<Response<Body>> {
 }
 
 async fn graphql_request(req: Request<Body>, server_ctx: &ServerContext) -> Result<Response<Body>> {
-  let upstream = server_ctx.blueprint.upstream.clone();
-  let allowed = upstream.get_allowed_headers();
-  let headers = create_allowed_headers(req.headers(), &allowed);
+  let headers = create_allowed_headers(req.headers(), &server_ctx.allowed_headers);
   let bytes = hyper::body::to_bytes(req.into_body()).await?;
   let request: async_graphql_hyper::GraphQLRequest = serde_json::from_slice(&bytes)?;
   let req_ctx = Arc::new(RequestContext::from(server_ctx).req_headers(headers));
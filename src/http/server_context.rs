@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use async_graphql::dynamic;
@@ -12,6 +13,7 @@ pub struct ServerContext {
   pub schema: dynamic::Schema,
   pub http_client: DefaultHttpClient,
   pub blueprint: Blueprint,
+  pub allowed_headers: BTreeSet<String>,
 }
 
 fn assign_data_loaders(blueprint: &mut Blueprint, http_client: DefaultHttpClient) -> &Blueprint {
@@ -33,10 +35,26 @@ fn assign_data_loaders(blueprint: &mut Blueprint, http_client: DefaultHttpClient
   blueprint
 }
 
+// Every request header that at least one field forwards, each field filters it down to its own set.
+fn allowed_headers(blueprint: &Blueprint) -> BTreeSet<String> {
+  let mut headers = blueprint.upstream.get_allowed_headers();
+  for def in blueprint.definitions.iter() {
+    if let Definition::ObjectTypeDefinition(def) = def {
+      for field in def.fields.iter() {
+        if let Some(Expression::Unsafe(Operation::Endpoint(req_template, _, _))) = &field.resolver {
+          headers.extend(req_template.endpoint.allowed_headers.iter().flatten().cloned());
+        }
+      }
+    }
+  }
+  headers
+}
+
 impl ServerContext {
   pub fn new(blueprint: Blueprint) -> Self {
     let http_client = DefaultHttpClient::new(blueprint.upstream.clone());
     let schema = assign_data_loaders(&mut blueprint.clone(), http_client.clone()).to_schema();
-    ServerContext { schema, http_client, blueprint }
+    let allowed_headers = allowed_headers(&blueprint);
+    ServerContext { schema, http_client, blueprint, allowed_headers }
   }
 }
@@ -0,0 +1,99 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use jaq_interpret::{Ctx, Filter, FilterT, ParseCtx, RcIter, Val};
+use serde_json::Value;
+
+/// A compiled jq filter, with the jq standard library (`map`, `select`, ...) available.
+#[derive(Clone)]
+pub struct JqFilter {
+  source: String,
+  filter: Arc<Filter>,
+}
+
+impl Debug for JqFilter {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("JqFilter").field(&self.source).finish()
+  }
+}
+
+impl JqFilter {
+  pub fn parse(source: &str) -> Result<Self, String> {
+    let (main, errors) = jaq_parse::parse(source, jaq_parse::main());
+    if let Some(error) = errors.first() {
+      return Err(match error.found() {
+        Some(token) => format!(
+          "invalid jq expression: unexpected '{}' at {}",
+          token,
+          error.span().start
+        ),
+        None => format!(
+          "invalid jq expression: unexpected end of input at {}",
+          error.span().start
+        ),
+      });
+    }
+    let main = main.ok_or_else(|| "invalid jq expression".to_string())?;
+
+    let mut defs = ParseCtx::new(Vec::new());
+    defs.insert_natives(jaq_core::core());
+    defs.insert_defs(jaq_std::std());
+    let filter = defs.compile(main);
+    if let Some((error, span)) = defs.errs.first() {
+      return Err(format!("invalid jq expression: {} at {}", error, span.start));
+    }
+    Ok(Self { source: source.to_string(), filter: Arc::new(filter) })
+  }
+
+  /// Runs the filter, a single output is returned as is and several outputs are collected into a list.
+  pub fn apply(&self, input: Value) -> Result<Value, String> {
+    let inputs = RcIter::new(core::iter::empty());
+    let mut outputs = self
+      .filter
+      .run((Ctx::new([], &inputs), Val::from(input)))
+      .map(|output| output.map(Value::from).map_err(|e| e.to_string()))
+      .collect::<Result<Vec<_>, _>>()?;
+    Ok(match outputs.len() {
+      0 => Value::Null,
+      1 => outputs.remove(0),
+      _ => Value::Array(outputs),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  use super::JqFilter;
+
+  #[test]
+  fn test_apply() {
+    let filter = JqFilter::parse(".data | map(.id)").unwrap();
+    let input = json!({"data": [{"id": 1}, {"id": 2}]});
+    assert_eq!(filter.apply(input).unwrap(), json!([1, 2]));
+
+    let filter = JqFilter::parse(".[] | .name").unwrap();
+    assert_eq!(
+      filter.apply(json!([{"name": "a"}, {"name": "b"}])).unwrap(),
+      json!(["a", "b"])
+    );
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    let error = |source: &str| JqFilter::parse(source).unwrap_err();
+    assert_eq!(
+      error(".data | map(.id"),
+      "invalid jq expression: unexpected end of input at 15"
+    );
+    assert_eq!(error("foo bar baz"), "invalid jq expression: unexpected 'bar' at 4");
+    assert_eq!(error("| .a"), "invalid jq expression: unexpected '|' at 0");
+    assert_eq!(error(".a || .b |"), "invalid jq expression: unexpected '|' at 4");
+    assert_eq!(
+      error("undefined_filter"),
+      "invalid jq expression: undefined filter at 0"
+    );
+  }
+}
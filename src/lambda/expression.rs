@@ -5,17 +5,22 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_graphql::dataloader::{DataLoader, NoCache};
+use base64::Engine;
 use serde::Serialize;
 use serde_json::Value;
 use thiserror::Error;
 
 use super::ResolverContextLike;
 use crate::config::group_by::GroupBy;
+use crate::config::Decode;
+use crate::endpoint::Endpoint;
 use crate::http::{max_age, DefaultHttpClient, HttpDataLoader};
 #[cfg(feature = "unsafe-js")]
 use crate::javascript;
 use crate::json::JsonLike;
 use crate::lambda::EvaluationContext;
+use crate::mustache::Mustache;
+use crate::path_string::PathString;
 use crate::request_template::RequestTemplate;
 
 #[derive(Clone, Debug)]
@@ -25,6 +30,7 @@ pub enum Expression {
   EqualTo(Box<Expression>, Box<Expression>),
   Unsafe(Operation),
   Input(Box<Expression>, Vec<String>),
+  Template(Value),
 }
 
 #[derive(Clone, Debug)]
@@ -79,6 +85,67 @@ impl<'a> From<crate::valid::ValidationError<&'a str>> for EvaluationError {
   }
 }
 
+fn render_template(value: &Value, ctx: &impl PathString) -> Value {
+  match value {
+    Value::String(template) => match Mustache::parse(template) {
+      Ok(mustache) => Value::String(mustache.render(ctx)),
+      Err(_) => value.clone(),
+    },
+    Value::Array(items) => Value::Array(items.iter().map(|item| render_template(item, ctx)).collect()),
+    Value::Object(map) => Value::Object(
+      map
+        .iter()
+        .map(|(key, item)| (key.clone(), render_template(item, ctx)))
+        .collect(),
+    ),
+    _ => value.clone(),
+  }
+}
+
+// The response is decoded first, so that the jq filter sees the decoded value.
+fn transform_body(endpoint: &Endpoint, body: async_graphql::Value) -> Result<async_graphql::Value> {
+  let body = decode_body(endpoint.decode.as_ref(), body)?;
+  match endpoint.jq.as_ref() {
+    Some(jq) => {
+      let input = body
+        .into_json()
+        .map_err(|e| EvaluationError::IOException(e.to_string()))?;
+      let output = jq
+        .apply(input)
+        .map_err(|e| EvaluationError::IOException(format!("failed to apply jq filter: {}", e)))?;
+      Ok(async_graphql::Value::from_json(output).map_err(|e| EvaluationError::IOException(e.to_string()))?)
+    }
+    None => Ok(body),
+  }
+}
+
+// Only string responses are decoded, anything else is assigned as it was received.
+fn decode_body(decode: Option<&Decode>, body: async_graphql::Value) -> Result<async_graphql::Value> {
+  let (Some(decode), async_graphql::Value::String(encoded)) = (decode, &body) else {
+    return Ok(body);
+  };
+  let bytes = match decode {
+    Decode::Base64 => base64::engine::general_purpose::STANDARD
+      .decode(encoded.trim())
+      .map_err(|e| EvaluationError::IOException(format!("failed to decode base64 response: {}", e)))?,
+    Decode::Hex => decode_hex(encoded.trim())
+      .ok_or_else(|| EvaluationError::IOException("failed to decode hex response".to_string()))?,
+  };
+  let decoded =
+    String::from_utf8(bytes).map_err(|e| EvaluationError::IOException(format!("decoded response: {}", e)))?;
+  Ok(async_graphql::Value::String(decoded))
+}
+
+fn decode_hex(encoded: &str) -> Option<Vec<u8>> {
+  if !encoded.len().is_multiple_of(2) {
+    return None;
+  }
+  (0..encoded.len())
+    .step_by(2)
+    .map(|i| encoded.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+    .collect()
+}
+
 impl Expression {
   pub fn eval<'a, Ctx: ResolverContextLike<'a> + Sync + Send>(
     &'a self,
@@ -95,6 +162,7 @@ impl Expression {
           Ok(inp.get_path(path).unwrap_or(&async_graphql::Value::Null).clone())
         }
         Expression::Literal(value) => Ok(serde_json::from_value(value.clone())?),
+        Expression::Template(value) => Ok(serde_json::from_value(render_template(value, ctx))?),
         Expression::EqualTo(left, right) => Ok(async_graphql::Value::from(
           left.eval(ctx).await? == right.eval(ctx).await?,
         )),
@@ -125,7 +193,7 @@ impl Expression {
                     ctx.req_ctx.set_min_max_age(max_age.as_secs());
                   }
                 }
-                return Ok(resp.body);
+                return transform_body(&req_template.endpoint, resp.body);
               }
 
               // Prepare for HTTP calls
@@ -134,19 +202,20 @@ impl Expression {
                 .execute(req)
                 .await
                 .map_err(|e| EvaluationError::IOException(e.to_string()))?;
+              if ctx.req_ctx.server.get_enable_cache_control() && res.status.is_success() {
+                if let Some(max_age) = max_age(&res) {
+                  ctx.req_ctx.set_min_max_age(max_age.as_secs());
+                }
+              }
+              let body = transform_body(&req_template.endpoint, res.body)?;
               if ctx.req_ctx.server.get_enable_http_validation() {
                 req_template
                   .endpoint
                   .output
-                  .validate(&res.body)
+                  .validate(&body)
                   .map_err(EvaluationError::from)?;
               }
-              if ctx.req_ctx.server.get_enable_cache_control() && res.status.is_success() {
-                if let Some(max_age) = max_age(&res) {
-                  ctx.req_ctx.set_min_max_age(max_age.as_secs());
-                }
-              }
-              Ok(res.body)
+              Ok(body)
             }
             Operation::JS(input, script) => {
               let result;
@@ -171,3 +240,47 @@ impl Expression {
     })
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use async_graphql::Value;
+
+  use super::{decode_body, transform_body};
+  use crate::config::Decode;
+  use crate::endpoint::Endpoint;
+  use crate::jq::JqFilter;
+
+  #[test]
+  fn test_decode_body() {
+    let body = Value::String("aGVsbG8gd29ybGQ=".to_string());
+    assert_eq!(
+      decode_body(Some(&Decode::Base64), body).unwrap(),
+      Value::String("hello world".to_string())
+    );
+
+    let body = Value::String("68656c6c6f".to_string());
+    assert_eq!(
+      decode_body(Some(&Decode::Hex), body).unwrap(),
+      Value::String("hello".to_string())
+    );
+
+    assert!(decode_body(Some(&Decode::Hex), Value::String("6g".to_string())).is_err());
+    assert_eq!(decode_body(Some(&Decode::Base64), Value::Null).unwrap(), Value::Null);
+  }
+
+  #[test]
+  fn test_transform_body_jq() {
+    let endpoint = Endpoint::new("http://localhost".to_string()).jq(Some(JqFilter::parse(".data.name").unwrap()));
+    let body = Value::from_json(serde_json::json!({"data": {"name": "Leanne"}})).unwrap();
+    assert_eq!(
+      transform_body(&endpoint, body).unwrap(),
+      Value::String("Leanne".to_string())
+    );
+
+    let endpoint = endpoint
+      .decode(Some(Decode::Base64))
+      .jq(Some(JqFilter::parse("fromjson | .id").unwrap()));
+    let body = Value::String("eyJpZCI6IDF9".to_string());
+    assert_eq!(transform_body(&endpoint, body).unwrap(), Value::from(1));
+  }
+}
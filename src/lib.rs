@@ -11,6 +11,7 @@ pub mod has_headers;
 pub mod http;
 #[cfg(feature = "unsafe-js")]
 pub mod javascript;
+pub mod jq;
 pub mod json;
 pub mod lambda;
 pub mod mustache;
@@ -9,6 +9,7 @@ pub struct Mustache(Vec<Segment>);
 pub enum Segment {
   Literal(String),
   Expression(Vec<String>),
+  Section(Vec<String>, Mustache),
 }
 
 impl From<Vec<Segment>> for Mustache {
@@ -26,6 +27,56 @@ impl Mustache {
     }
   }
 
+  // Templates that parse to literals only (eg: a plain URL) render to themselves.
+  pub fn is_templated(str: &str) -> bool {
+    Mustache::parse(str).is_ok_and(|mustache| !mustache.expression_segments().is_empty())
+  }
+
+  pub fn expression_segments(&self) -> Vec<&Vec<String>> {
+    match self {
+      Mustache(segments) => segments
+        .iter()
+        .flat_map(|segment| match segment {
+          Segment::Expression(parts) => vec![parts],
+          Segment::Section(parts, inner) => std::iter::once(parts).chain(inner.expression_segments()).collect(),
+          _ => vec![],
+        })
+        .collect(),
+    }
+  }
+
+  // Section heads, and the expressions inside a section that only render when the head is present.
+  pub fn guarded_segments(&self) -> Vec<&Vec<String>> {
+    match self {
+      Mustache(segments) => segments
+        .iter()
+        .flat_map(|segment| match segment {
+          Segment::Section(parts, inner) => std::iter::once(parts)
+            .chain(
+              inner
+                .expression_segments()
+                .into_iter()
+                .filter(|inner_parts| inner_parts.starts_with(parts)),
+            )
+            .collect(),
+          _ => vec![],
+        })
+        .collect(),
+    }
+  }
+
+  pub fn section_segments(&self) -> Vec<&Vec<String>> {
+    match self {
+      Mustache(segments) => segments
+        .iter()
+        .flat_map(|segment| match segment {
+          Segment::Section(parts, inner) => std::iter::once(parts).chain(inner.section_segments()).collect(),
+          _ => vec![],
+        })
+        .collect(),
+    }
+  }
+
   pub fn render(&self, value: &impl PathString) -> String {
     match self {
       Mustache(segments) => segments
@@ -33,6 +84,10 @@ impl Mustache {
         .map(|segment| match segment {
           Segment::Literal(text) => text.clone(),
           Segment::Expression(parts) => value.path_string(parts).map(|a| a.to_string()).unwrap_or_default(),
+          Segment::Section(parts, inner) => match value.path_string(parts) {
+            Some(a) if !a.is_empty() => inner.render(value),
+            _ => String::new(),
+          },
         })
         .collect(),
     }
@@ -44,7 +99,7 @@ fn parse_name(input: &str) -> IResult<&str, String> {
     nom::sequence::tuple((
       nom::character::complete::multispace0,
       nom::character::complete::alpha1,
-      nom::character::complete::alphanumeric0,
+      nom::bytes::complete::take_while(|c: char| c.is_alphanumeric() || c == '_' || c == '-'),
       nom::character::complete::multispace0,
     )),
     |(_, a, b, _)| format!("{}{}", a, b),
@@ -62,13 +117,26 @@ fn parse_expression(input: &str) -> IResult<&str, Vec<String>> {
   )(input)
 }
 
+fn parse_section(input: &str) -> IResult<&str, Segment> {
+  let path = |input| nom::multi::separated_list1(nom::character::complete::char('.'), parse_name)(input);
+  let (input, (_, parts, _)) =
+    nom::sequence::tuple((nom::bytes::complete::tag("{{#"), path, nom::bytes::complete::tag("}}")))(input)?;
+  let (input, inner) = nom::multi::many0(parse_segment)(input)?;
+  let (input, _) = nom::combinator::verify(
+    nom::sequence::delimited(nom::bytes::complete::tag("{{/"), path, nom::bytes::complete::tag("}}")),
+    |closing: &Vec<String>| closing == &parts,
+  )(input)?;
+  Ok((input, Segment::Section(parts, Mustache(inner))))
+}
+
 fn parse_segment(input: &str) -> IResult<&str, Segment> {
+  let section = parse_section;
   let expression = nom::combinator::map(parse_expression, Segment::Expression);
   let literal = nom::combinator::map(nom::bytes::complete::take_while1(|c| c != '{'), |r: &str| {
     Segment::Literal(r.to_string())
   });
 
-  nom::branch::alt((expression, literal))(input)
+  nom::branch::alt((section, expression, literal))(input)
 }
 
 fn parse_mustache(input: &str) -> IResult<&str, Mustache> {
@@ -131,6 +199,58 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_with_underscores() {
+      let s = "{{env.API_KEY}}";
+      let mustache: Mustache = Mustache::parse(s).unwrap();
+      assert_eq!(
+        mustache,
+        Mustache::from(vec![Segment::Expression(vec![
+          "env".to_string(),
+          "API_KEY".to_string()
+        ])])
+      );
+    }
+
+    #[test]
+    fn test_with_hyphens() {
+      let s = "{{headers.x-tenant-url}}/users";
+      let mustache: Mustache = Mustache::parse(s).unwrap();
+      assert_eq!(
+        mustache,
+        Mustache::from(vec![
+          Segment::Expression(vec!["headers".to_string(), "x-tenant-url".to_string()]),
+          Segment::Literal("/users".to_string())
+        ])
+      );
+    }
+
+    #[test]
+    fn test_with_section() {
+      let s = "/users{{#args.id}}/{{args.id}}{{/args.id}}";
+      let mustache: Mustache = Mustache::parse(s).unwrap();
+      assert_eq!(
+        mustache,
+        Mustache::from(vec![
+          Segment::Literal("/users".to_string()),
+          Segment::Section(
+            vec!["args".to_string(), "id".to_string()],
+            Mustache::from(vec![
+              Segment::Literal("/".to_string()),
+              Segment::Expression(vec!["args".to_string(), "id".to_string()])
+            ])
+          )
+        ])
+      );
+    }
+
+    #[test]
+    fn test_with_unclosed_section() {
+      let s = "{{#args.id}}/{{args.id}}{{/args.name}}";
+      let mustache: Mustache = Mustache::parse(s).unwrap();
+      assert_eq!(mustache, Mustache::from(vec![Segment::Literal(s.to_string())]));
+    }
+
     #[test]
     fn test_parse_expression_with_valid_input() {
       let result = Mustache::parse("{{ foo.bar }} extra").unwrap();
@@ -190,6 +310,14 @@ mod tests {
       let mustache = Mustache::parse("123").unwrap();
       assert_eq!(mustache, Mustache::from(vec![Segment::Literal("123".to_string())]));
     }
+
+    #[test]
+    fn test_is_templated() {
+      assert!(Mustache::is_templated("{{env.API_URL}}/api"));
+      assert!(Mustache::is_templated("{{#args.id}}{{args.id}}{{/args.id}}"));
+      assert!(!Mustache::is_templated("http://localhost:8080"));
+      assert!(!Mustache::is_templated("{{unclosed"));
+    }
   }
   mod render {
     use std::borrow::Cow;
@@ -208,6 +336,20 @@ mod tests {
       assert_eq!(result, "/v1/templates?project-id=123");
     }
 
+    #[test]
+    fn test_render_section_present() {
+      let mustache = Mustache::parse("/users{{#args.id}}/{{args.id}}{{/args.id}}").unwrap();
+      let ctx = json!({"args": {"id": 1}});
+      assert_eq!(mustache.render(&ctx), "/users/1");
+    }
+
+    #[test]
+    fn test_render_section_absent() {
+      let mustache = Mustache::parse("/users{{#args.id}}/{{args.id}}{{/args.id}}").unwrap();
+      let ctx = json!({"args": {}});
+      assert_eq!(mustache.render(&ctx), "/users");
+    }
+
     #[test]
     fn test_render_mixed() {
       struct DummyPath;
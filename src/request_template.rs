@@ -3,6 +3,7 @@ use hyper::HeaderMap;
 use reqwest::header::{HeaderName, HeaderValue};
 use url::Url;
 
+use crate::config::QueryEncoding;
 use crate::endpoint::Endpoint;
 use crate::has_headers::HasHeaders;
 use crate::mustache::Mustache;
@@ -25,7 +26,7 @@ impl RequestTemplate {
     let mut url = url::Url::parse(root_url.as_str())?;
     url
       .query_pairs_mut()
-      .extend_pairs(self.query.iter().map(|(k, v)| (k.as_str(), v.render(ctx))));
+      .extend_pairs(self.query.iter().flat_map(|(k, v)| self.expand_query(k, v.render(ctx))));
 
     let query_string = url
       .query_pairs()
@@ -43,6 +44,28 @@ impl RequestTemplate {
     Ok(url)
   }
 
+  // Lists are rendered as JSON, an expanded param is written the way the endpoint's query encoding asks for.
+  fn expand_query(&self, key: &str, value: String) -> Vec<(String, String)> {
+    if !self.endpoint.expand.contains(key) {
+      return vec![(key.to_string(), value)];
+    }
+    let items: Vec<String> = match serde_json::from_str::<Vec<serde_json::Value>>(&value) {
+      Ok(items) => items
+        .into_iter()
+        .map(|item| match item {
+          serde_json::Value::String(item) => item,
+          item => item.to_string(),
+        })
+        .collect(),
+      Err(_) => return vec![(key.to_string(), value)],
+    };
+    match self.endpoint.query_encoding {
+      QueryEncoding::Repeat => items.into_iter().map(|item| (key.to_string(), item)).collect(),
+      QueryEncoding::Comma => vec![(key.to_string(), items.join(","))],
+      QueryEncoding::Brackets => items.into_iter().map(|item| (format!("{}[]", key), item)).collect(),
+    }
+  }
+
   fn eval_headers<C: PathString>(&self, ctx: &C) -> HeaderMap {
     let mut header_map = HeaderMap::new();
 
@@ -65,11 +88,22 @@ impl RequestTemplate {
       .unwrap_or(reqwest::Body::from("".to_string()))
   }
 
+  fn forwarded_headers(&self, headers: &HeaderMap) -> HeaderMap {
+    match self.endpoint.allowed_headers.as_ref() {
+      Some(allowed) => headers
+        .iter()
+        .filter(|(name, _)| allowed.contains(name.as_str()))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect(),
+      None => headers.to_owned(),
+    }
+  }
+
   /// A high-performance way to reliably create a request
   pub fn to_request<C: PathString + HasHeaders>(&self, ctx: &C) -> anyhow::Result<reqwest::Request> {
     let url = self.eval_url(ctx)?;
     let mut header_map = self.eval_headers(ctx);
-    header_map.extend(ctx.headers().to_owned());
+    header_map.extend(self.forwarded_headers(ctx.headers()));
     header_map.insert(
       reqwest::header::CONTENT_TYPE,
       HeaderValue::from_static("application/json"),
@@ -129,6 +163,8 @@ mod tests {
   use pretty_assertions::assert_eq;
   use serde_json::json;
 
+  use crate::config::QueryEncoding;
+  use crate::endpoint::Endpoint;
   use crate::mustache::Mustache;
   use crate::request_template::RequestTemplate;
   #[derive(Setters)]
@@ -224,6 +260,37 @@ mod tests {
     assert_eq!(req.url().to_string(), "http://localhost:3000/?foo=0&bar=1&baz=2");
   }
   #[test]
+  fn test_url_query_params_expand() {
+    // list arguments render as JSON, the literals stand in for `{{args.ids}}`
+    let query = vec![
+      ("id".to_string(), Mustache::parse("[1,2]").unwrap()),
+      ("tag".to_string(), Mustache::parse(r#"["a","b"]"#).unwrap()),
+      ("raw".to_string(), Mustache::parse("[3]").unwrap()),
+    ];
+    let endpoint = Endpoint::new("http://localhost:3000/".to_string())
+      .expand(["id".to_string(), "tag".to_string()].into_iter().collect());
+    let tmpl = RequestTemplate::new("http://localhost:3000/")
+      .unwrap()
+      .query(query)
+      .endpoint(endpoint);
+    let req = tmpl.to_request(&Context::default()).unwrap();
+    assert_eq!(
+      req.url().to_string(),
+      "http://localhost:3000/?id=1&id=2&tag=a&tag=b&raw=[3]"
+    );
+
+    let endpoint = tmpl.endpoint.clone().query_encoding(QueryEncoding::Comma);
+    let req = tmpl.clone().endpoint(endpoint).to_request(&Context::default()).unwrap();
+    assert_eq!(req.url().to_string(), "http://localhost:3000/?id=1,2&tag=a,b&raw=[3]");
+
+    let endpoint = tmpl.endpoint.clone().query_encoding(QueryEncoding::Brackets);
+    let req = tmpl.clone().endpoint(endpoint).to_request(&Context::default()).unwrap();
+    assert_eq!(
+      req.url().to_string(),
+      "http://localhost:3000/?id[]=1&id[]=2&tag[]=a&tag[]=b&raw=[3]"
+    );
+  }
+  #[test]
   fn test_headers() {
     let headers = vec![
       ("foo".to_string(), Mustache::parse("foo").unwrap()),
@@ -259,6 +326,26 @@ mod tests {
     assert_eq!(req.headers().get("baz").unwrap(), "2");
   }
   #[test]
+  fn test_forwarded_headers_per_field() {
+    let mut headers = HeaderMap::new();
+    headers.insert("authorization", "Bearer token".parse().unwrap());
+    headers.insert("x-request-id", "1".parse().unwrap());
+    let ctx = Context::default().headers(headers);
+    let template = |allowed: &[&str]| {
+      let allowed = allowed.iter().map(|name| name.to_string()).collect();
+      RequestTemplate::try_from(Endpoint::new("http://localhost:3000".to_string()).allowed_headers(Some(allowed)))
+        .unwrap()
+    };
+
+    let req = template(&["authorization", "x-request-id"]).to_request(&ctx).unwrap();
+    assert_eq!(req.headers().get("authorization").unwrap(), "Bearer token");
+    assert_eq!(req.headers().get("x-request-id").unwrap(), "1");
+
+    let req = template(&["x-request-id"]).to_request(&ctx).unwrap();
+    assert!(req.headers().get("authorization").is_none());
+    assert_eq!(req.headers().get("x-request-id").unwrap(), "1");
+  }
+  #[test]
   fn test_method() {
     let tmpl = RequestTemplate::new("http://localhost:3000")
       .unwrap()